@@ -1,61 +1,553 @@
-use std::time::Instant;
+use std::{
+    io::{IsTerminal, Write, stdout},
+    sync::mpsc::{Receiver, TryRecvError},
+    time::{Duration, Instant},
+};
 
 use crate::{
     core::{
-        bounds::{Axis, graph_dims, terminal_geometry},
-        config::Config,
+        bounds::{Axis, Scale, graph_dims, terminal_geometry},
+        config::{Baseline, Config, ConfigBuilder, Extent, Overlap, PlotStyle},
         constants::{
-            BORDER_WIDTH, BRAILLE_HORIZONTAL_RESOLUTION, DECIMAL_PRECISION, LABEL_GUTTER,
-            MIN_GRAPH_HEIGHT, MIN_GRAPH_WIDTH,
+            BORDER_WIDTH, DECIMAL_PRECISION, FRAME_ROWS, LABEL_GUTTER, MIN_GRAPH_HEIGHT,
+            MIN_GRAPH_WIDTH,
+        },
+        data::{
+            ColumnRef, ColumnSpec, CsvTable, DataTimeStep, ParseCsvError, Series, follow_lines,
+            read_csv_table_from_path,
         },
-        data::{DataTimeStep, read_csv_from_path},
-        error::GraphError,
+        error::{ConfigError, GraphError},
         rng::Lcg,
+        time::TimeFormat,
+    },
+    render::{
+        Aggregate, Binner, Histogram, Layout, Panel, Renderer, Strategy, braille::BraillePlot,
+        build_sparkline, preprocess_series, preprocess_to_braille,
     },
-    render::{Binner, Renderer, Strategy, preprocess_to_braille},
 };
 
-use super::parse::{CsvArgs, DemoArgs};
+use super::parse::{CsvArgs, DemoArgs, HistArgs, SparklineArgs};
+
+pub fn csv(a: &CsvArgs) -> Result<(), GraphError> {
+    if a.follow {
+        return csv_follow(a);
+    }
 
-pub fn csv(a: CsvArgs) -> Result<(), GraphError> {
     let t_ingest = Instant::now();
-    let mut data = read_csv_from_path(&a.file)?;
-    if !data.windows(2).all(|w| w[0].time <= w[1].time) {
-        data.sort_by(|l, r| {
-            l.time
-                .partial_cmp(&r.time)
-                .unwrap_or(std::cmp::Ordering::Equal)
+    let table = read_csv_table_from_path(&a.file)?;
+    let series = table.select(&column_specs(a, &table))?;
+    let dur_ingest = t_ingest.elapsed().as_micros();
+
+    if a.debug {
+        eprintln!(
+            "CSV ingest: {dur_ingest} µs   ({} rows, {} series)",
+            table.time.len(),
+            series.len()
+        );
+    }
+    if a.interactive {
+        return super::view::explore(a, &series, table.time_kind);
+    }
+    // one binner per series: binners cache per data set
+    let mut binners: Vec<_> = series
+        .iter()
+        .map(|_| Binner::new(a.bin_type).aggregate(a.aggregate))
+        .collect();
+    let mut renderer = if stdout().is_terminal() {
+        Renderer::full()
+    } else {
+        Renderer::plain()
+    };
+    draw(
+        a,
+        &series,
+        &mut binners,
+        table.time_kind,
+        false,
+        &mut renderer,
+        &mut stdout().lock(),
+    )
+}
+
+/// Bin `series` and render them: overlaid on shared axes, or one panel
+/// each with `--layout`.
+pub(super) fn draw(
+    a: &CsvArgs,
+    series: &[Series],
+    binners: &mut [Binner],
+    time_kind: TimeFormat,
+    fill_width: bool,
+    renderer: &mut Renderer,
+    out: &mut impl Write,
+) -> Result<(), GraphError> {
+    let Some(layout) = a.layout else {
+        let cfg = csv_config(a, series, time_kind, fill_width)?;
+        let mut binned = Vec::with_capacity(series.len());
+        for (s, b) in series.iter().zip(binners.iter_mut()) {
+            bin_series(a, s, b, &cfg, &mut binned);
+        }
+        let mut plot = preprocess_series(&binned, &cfg, a.bridge)?;
+        label_span(a, &mut plot, binners);
+        return renderer.render_to(out, &cfg, &plot);
+    };
+
+    let cfgs = panel_configs(a, layout, series, time_kind)?;
+    let mut plots = Vec::with_capacity(series.len());
+    for ((s, b), cfg) in series.iter().zip(binners).zip(&cfgs) {
+        let mut binned = Vec::with_capacity(2);
+        bin_series(a, s, b, cfg, &mut binned);
+        let mut plot = preprocess_series(&binned, cfg, a.bridge)?;
+        label_span(a, &mut plot, std::slice::from_ref(b));
+        plots.push(plot);
+    }
+    let panels: Vec<_> = cfgs
+        .iter()
+        .zip(&plots)
+        .map(|(cfg, plot)| Panel { cfg, plot })
+        .collect();
+    renderer.render_panels_to(out, layout, &panels)
+}
+
+/// `--column` selection, or the default: legacy `time,min[,max]` for narrow
+/// files, every column otherwise.
+fn column_specs(a: &CsvArgs, table: &CsvTable) -> Vec<ColumnSpec> {
+    if !a.columns.is_empty() {
+        a.columns.clone()
+    } else if table.headers.len() <= 3 {
+        vec![ColumnSpec {
+            min: ColumnRef::Index(2),
+            max: (table.headers.len() > 2).then_some(ColumnRef::Index(3)),
+        }]
+    } else {
+        table.column_specs()
+    }
+}
+
+/// Label a binned plot by the time its buckets cover rather than by their
+/// centres, so the first and last labels show the real range.  Line and
+/// scatter plots already span `x_range` or the data.
+pub(super) fn label_span(a: &CsvArgs, plot: &mut BraillePlot, binners: &[Binner]) {
+    if matches!(a.style, PlotStyle::Line | PlotStyle::Scatter { .. }) {
+        return;
+    }
+    let span = binners
+        .iter()
+        .filter_map(Binner::span)
+        .reduce(|(a0, a1), (b0, b1)| (a0.min(b0), a1.max(b1)));
+    if span.is_some() {
+        plot.x_span = span;
+    }
+}
+
+/// Bin one series: its envelope, or with `--aggregate` a band plus its
+/// central value as two series.  Scatter plots keep every row.  With
+/// `--gap`, gaps are found between the rows, not between the bins.
+pub(super) fn bin_series(
+    a: &CsvArgs,
+    s: &Series,
+    binner: &mut Binner,
+    cfg: &Config,
+    out: &mut Vec<Series>,
+) {
+    if let PlotStyle::Scatter { .. } = a.style {
+        out.push(s.clone());
+        return;
+    }
+    let (band, center) = binner.bin_with_center(&s.steps, cfg);
+    let breaks = a
+        .gap
+        .map(|n| binner.breaks(&s.steps, n))
+        .unwrap_or_default();
+    if center.is_empty() {
+        out.push(Series {
+            name: s.name.clone(),
+            steps: band,
+            breaks,
         });
+        return;
     }
-    let dur_ingest = t_ingest.elapsed().as_micros();
+    let (band_name, center_name) = match a.aggregate {
+        Aggregate::Percentile { lo, hi } => (format!("p{lo}–p{hi}"), "median"),
+        _ => ("±σ".to_owned(), "mean"),
+    };
+    let label = |what: &str| format!("{} {what}", s.name).trim_start().to_owned();
+    out.push(Series {
+        name: label(&band_name),
+        steps: band,
+        breaks: breaks.clone(),
+    });
+    out.push(Series {
+        name: label(center_name),
+        steps: center,
+        breaks,
+    });
+}
 
-    // config
-    let (y_lo, y_hi) = Axis::Y.bounds(&data);
-    let term = terminal_geometry();
-    let (x_chars, y_chars) = graph_dims(term, data.len());
+/// Frame configuration for the current data and terminal size.  The plot
+/// is as wide as the samples inside the x range need, or as the terminal
+/// with `fill_width`, `--style line` or `--style scatter`.
+pub(super) fn csv_config(
+    a: &CsvArgs,
+    series: &[Series],
+    time_kind: TimeFormat,
+    fill_width: bool,
+) -> Result<Config, GraphError> {
+    Ok(csv_builder(a, series, time_kind, fill_width).build()?)
+}
+
+/// [`csv_config`] before `build`, for callers that override parts of it.
+fn csv_builder(
+    a: &CsvArgs,
+    series: &[Series],
+    time_kind: TimeFormat,
+    fill_width: bool,
+) -> ConfigBuilder {
+    let steps = || series.iter().flat_map(|s| &s.steps);
+    let x_range = (a.x_min.is_some() || a.x_max.is_some()).then(|| {
+        let (lo, hi) = Axis::X.bounds(steps());
+        (a.x_min.unwrap_or(lo), a.x_max.unwrap_or(hi))
+    });
+    let in_range = |p: &&DataTimeStep| x_range.is_none_or(|(lo, hi)| (lo..=hi).contains(&p.time));
+
+    let (y_lo, y_hi) = Axis::Y.bounds_in(steps().filter(in_range), a.y_scale);
+    let placed = matches!(a.style, PlotStyle::Line | PlotStyle::Scatter { .. });
+    let samples = if fill_width || placed {
+        usize::MAX
+    } else {
+        series
+            .iter()
+            .map(|s| s.steps.iter().filter(in_range).count())
+            .max()
+            .unwrap_or(0)
+    };
+    let (x_chars, y_chars) = graph_dims(terminal_geometry(), samples, a.out.glyphs);
 
     let mut b = Config::builder(x_chars, y_chars)
-        .title(a.title)
+        .title(a.title.clone())
         .subtitle_opt(&a.subtitle)
-        .color(a.color)
+        .color(a.out.fg)
+        .palette(a.colors.clone())
+        .legend(!a.no_legend)
+        .x_format(a.x_format.unwrap_or(time_kind))
+        .gridlines(a.grid)
+        .style(match a.style {
+            PlotStyle::Area(_) => PlotStyle::Area(a.baseline),
+            PlotStyle::Scatter { .. } => PlotStyle::Scatter { density: a.density },
+            s => s,
+        })
+        .colored(a.out.color.enabled())
+        .glyphs(a.out.glyphs)
+        .y_scale(a.y_scale)
         .y_min(a.y_min.unwrap_or(y_lo))
         .y_max(a.y_max.unwrap_or(y_hi));
 
-    if let (Some(lo), Some(hi)) = (a.x_min, a.x_max) {
+    if let Some((lo, hi)) = x_range {
         b = b.x_range(lo, hi);
     }
-    let cfg = b.build()?;
+    if let Some(w) = a.out.width {
+        b = b.width(w);
+    }
+    if let Some(h) = a.out.height {
+        b = b.height(h);
+    }
+    if let Some(n) = a.gap {
+        b = b.gap_threshold(n);
+    }
+    if let Some(c) = a.below_color {
+        b = b.below_color(c);
+    }
+    if let Some(c) = a.overlap_color {
+        b = b.overlap(Overlap::Color(c));
+    }
+    b
+}
 
-    // transform + render
-    let mut binner = Binner::new(a.bin_type);
-    let binned = binner.bin(&data, &cfg);
-    let plot = preprocess_to_braille(&binned, &cfg, a.bridge)?;
-    if a.debug {
-        eprintln!("CSV ingest: {dur_ingest} µs   ({} rows)", plot.steps.len());
+/// `--layout`: one panel per series, each with its own y range and color,
+/// all over the time range of the whole data set and with equally wide y
+/// labels, so that stacked panels line up.  `--width`/`--height` size the
+/// whole layout, or with a `d` suffix each panel.
+fn panel_configs(
+    a: &CsvArgs,
+    layout: Layout,
+    series: &[Series],
+    time_kind: TimeFormat,
+) -> Result<Vec<Config>, GraphError> {
+    let (lo, hi) = Axis::X.bounds(series.iter().flat_map(|s| &s.steps));
+    let x_range = (a.x_min.unwrap_or(lo), a.x_max.unwrap_or(hi));
+    let (term_w, term_h) = terminal_geometry();
+    let outer = |e: Option<Extent>, term: usize| match e {
+        Some(Extent::Outer(n)) => n,
+        _ => term,
+    };
+    let sizes = layout.panel_sizes(
+        series.len(),
+        (
+            outer(a.out.width, usize::from(term_w.0)),
+            outer(a.out.height, usize::from(term_h.0).saturating_sub(1)),
+        ),
+    );
+    let colors = csv_config(a, series, time_kind, true)?;
+    let panel = |i: usize, label_w: usize| {
+        let (w, h) = sizes[i];
+        let dots_or = |e: Option<Extent>, n| match e {
+            Some(d @ Extent::Dots(_)) => d,
+            _ => Extent::Outer(n),
+        };
+        csv_builder(a, &series[i..=i], time_kind, true)
+            .title(series[i].name.clone())
+            .palette(Vec::new())
+            .color(colors.series_color(i))
+            .x_range(x_range.0, x_range.1)
+            .width(dots_or(a.out.width, w))
+            .height(dots_or(a.out.height, h))
+            .min_label_width(label_w)
+            .build()
+    };
+
+    let mut label_w = 0;
+    for i in 0..series.len() {
+        label_w = label_w.max(panel(i, 0)?.label_width());
     }
-    Renderer::full().render(&cfg, &plot)
+    Ok((0..series.len())
+        .map(|i| panel(i, label_w))
+        .collect::<Result<_, _>>()?)
+}
+
+/// `csv --follow`: parse rows as they are appended and redraw only the
+/// lines that changed.
+fn csv_follow(a: &CsvArgs) -> Result<(), GraphError> {
+    let lines = follow_lines(&a.file, frame_pause(a))?;
+    // Without a terminal there is no cursor to move: append whole frames.
+    let renderer = if stdout().is_terminal() {
+        Renderer::delta()
+    } else {
+        Renderer::plain()
+    };
+    follow(a, &lines, renderer, &mut stdout().lock())
+}
+
+/// Pause between two frames at `--fps`.
+fn frame_pause(a: &CsvArgs) -> Duration {
+    Duration::from_micros(1_000_000 / a.fps.max(1))
 }
 
+/// Plot `lines` as they arrive, until the sender hangs up.  A frame is only
+/// drawn when new rows arrived or the terminal was resized, so idle polls
+/// add nothing to a plain (appending) output.
+fn follow(
+    a: &CsvArgs,
+    lines: &Receiver<Result<Vec<u8>, ParseCsvError>>,
+    mut renderer: Renderer,
+    out: &mut impl Write,
+) -> Result<(), GraphError> {
+    let mut table = CsvTable::new();
+    let mut line_no = 0usize;
+    let mut specs = Vec::new();
+    let mut series: Vec<Series> = Vec::new();
+    let mut binners: Vec<Binner> = Vec::new();
+    let mut term = terminal_geometry();
+    let mut open = true;
+
+    while open {
+        // 1. Drain whatever arrived since the last frame.
+        let mut dirty = false;
+        loop {
+            match lines.try_recv() {
+                // keep-alive while the file is idle
+                Ok(Ok(line)) if line.is_empty() => {}
+                Ok(line) => {
+                    line_no += 1;
+                    // A bad row leaves no trace in the table: skip it and
+                    // keep following.
+                    match table.push_line(&mut line?, line_no) {
+                        Ok(()) => dirty = true,
+                        Err(e) => eprintln!("warning: skipping {e}"),
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    open = false;
+                    break;
+                }
+            }
+        }
+
+        // 2. Hand new rows over to the per-series histories.
+        if !table.time.is_empty() {
+            if series.is_empty() {
+                specs = column_specs(a, &table);
+                for spec in &specs {
+                    series.push(Series {
+                        name: table.series_name(spec)?,
+                        steps: Vec::new(),
+                        breaks: Vec::new(),
+                    });
+                    binners.push(Binner::new(a.bin_type).aggregate(a.aggregate));
+                }
+            }
+            for (spec, s) in specs.iter().zip(&mut series) {
+                table.append_series(spec, &mut s.steps)?;
+            }
+            table.clear_rows();
+        }
+
+        // 3. Trim to the window and redraw.
+        let now = terminal_geometry();
+        if now != term {
+            term = now;
+            dirty = true;
+        }
+        if dirty && series.iter().any(|s| !s.steps.is_empty()) {
+            let window = match (a.scroll, a.layout) {
+                (true, Some(layout)) => panel_configs(a, layout, &series, table.time_kind)?
+                    .iter()
+                    .map(Config::dot_columns)
+                    .min(),
+                (true, None) => Some(csv_config(a, &series, table.time_kind, true)?.dot_columns()),
+                (false, _) => a.window,
+            };
+            if let Some(w) = window {
+                for s in &mut series {
+                    let excess = s.steps.len().saturating_sub(w);
+                    s.steps.drain(..excess);
+                }
+            }
+
+            // Full terminal width from the first row on: the trace grows
+            // left to right, then gets binned once it overflows.
+            draw(
+                a,
+                &series,
+                &mut binners,
+                table.time_kind,
+                true,
+                &mut renderer,
+                out,
+            )?;
+        }
+
+        if open {
+            std::thread::sleep(frame_pause(a));
+        }
+    }
+    Ok(())
+}
+
+pub fn hist(a: &HistArgs) -> Result<(), GraphError> {
+    let table = read_csv_table_from_path(&a.file)?;
+    let spec = a.column.clone().unwrap_or(ColumnSpec {
+        min: ColumnRef::Index(2),
+        max: None,
+    });
+    let series = table.series(&spec)?;
+    let mut values = Vec::with_capacity(series.steps.len());
+    for p in &series.steps {
+        values.push(p.min);
+        if spec.max.is_some() {
+            values.push(p.max);
+        }
+    }
+
+    // Never more buckets than dot columns; the label width (and so the plot
+    // width) depends on the tallest bar, hence the second pass.
+    let (x_chars, y_chars) = graph_dims(terminal_geometry(), usize::MAX, a.out.glyphs);
+    let mut max_buckets = x_chars * a.out.glyphs.cols();
+    let (hist, cfg) = loop {
+        let hist = Histogram::new(&values, a.bins, max_buckets)?;
+        let cfg = hist_config(a, &series.name, &hist, x_chars, y_chars)?;
+        let cols = cfg.dot_columns();
+        if hist.counts.len() <= cols || max_buckets <= cols {
+            break (hist, cfg);
+        }
+        max_buckets = cols;
+    };
+
+    let steps = hist.steps(cfg.dot_columns(), a.log);
+    let mut plot = preprocess_to_braille(&steps, &cfg, false)?;
+    plot.x_span = Some((hist.lo, hist.hi));
+    if stdout().is_terminal() {
+        Renderer::full().render(&cfg, &plot)
+    } else {
+        Renderer::plain().render(&cfg, &plot)
+    }
+}
+
+/// Bars from zero to the tallest bucket, summary statistics as subtitle.
+fn hist_config(
+    a: &HistArgs,
+    name: &str,
+    hist: &Histogram,
+    x_chars: usize,
+    y_chars: usize,
+) -> Result<Config, GraphError> {
+    let subtitle = a.subtitle.clone().unwrap_or_else(|| {
+        let summary = hist.summary(&a.percentiles);
+        if a.log {
+            format!("log₁₀(1+count) {summary}")
+        } else {
+            summary
+        }
+    });
+    let mut b = Config::builder(x_chars, y_chars)
+        .title(a.title.as_deref().unwrap_or(name))
+        .subtitle(subtitle)
+        .color(a.out.fg)
+        .colored(a.out.color.enabled())
+        .glyphs(a.out.glyphs)
+        .y_range(0.0..=hist.peak(a.log))
+        .style(PlotStyle::Area(Baseline::Zero));
+    if let Some(w) = a.out.width {
+        b = b.width(w);
+    }
+    if let Some(h) = a.out.height {
+        b = b.height(h);
+    }
+    Ok(b.build()?)
+}
+
+/// Bare plot cells for prompts and status bars: no frame, labels or cursor
+/// control, at exactly the requested size (20×1 characters by default).
+pub fn sparkline(a: &SparklineArgs) -> Result<(), GraphError> {
+    let chars = |e: Option<Extent>, dots_per_char, default| {
+        e.map_or(default, |e| e.plot_chars(dots_per_char, 0))
+    };
+    let width = chars(a.out.width, a.out.glyphs.cols(), 20);
+    let height = chars(a.out.height, a.out.glyphs.rows(), 1);
+    if width == 0 || height == 0 {
+        return Err(ConfigError::ZeroSize { width, height }.into());
+    }
+    let table = read_csv_table_from_path(&a.file)?;
+    let spec = a.column.clone().unwrap_or(ColumnSpec {
+        min: ColumnRef::Index(2),
+        max: None,
+    });
+    let series = table.series(&spec)?;
+    if series.steps.is_empty() {
+        return Err(GraphError::EmptyData);
+    }
+
+    let (y_lo, y_hi) = Axis::Y.bounds(&series.steps);
+    let (x_lo, x_hi) = Axis::X.bounds(&series.steps);
+    let cfg = Config::builder(width, height)
+        .color(a.out.fg)
+        .colored(a.out.color.enabled())
+        .glyphs(a.out.glyphs)
+        .style(a.style)
+        .x_range(x_lo, x_hi)
+        .y_min(a.y_min.unwrap_or(y_lo))
+        .y_max(a.y_max.unwrap_or(y_hi))
+        .build()?;
+
+    let steps = match a.style {
+        PlotStyle::Scatter { .. } => series.steps,
+        _ => Binner::new(Strategy::Index).bin(&series.steps, &cfg),
+    };
+    let plot = preprocess_to_braille(&steps, &cfg, false)?;
+    println!("{}", build_sparkline(&cfg, &plot));
+    Ok(())
+}
+
+#[allow(clippy::cast_precision_loss)] // frame counts and timings are far below 2^52
 pub fn demo(a: &DemoArgs) -> Result<(), GraphError> {
     use crate::core::bounds::{self, Axis};
 
@@ -67,9 +559,13 @@ pub fn demo(a: &DemoArgs) -> Result<(), GraphError> {
     // Seed with enough points to fill current terminal width
     let term = bounds::terminal_geometry();
     let label_w = 4; // safe lower bound until we know y-range
-    let cols_available = term.0.0 as usize - BORDER_WIDTH - LABEL_GUTTER - label_w - 1; // safety margin
+    let frame_w = BORDER_WIDTH + LABEL_GUTTER + label_w;
+    let cols_available = a.out.width.map_or(
+        term.0.0 as usize - frame_w - 1, // safety margin
+        |w| w.plot_chars(a.out.glyphs.cols(), frame_w),
+    );
     let char_cols = cols_available.max(MIN_GRAPH_WIDTH);
-    let points_needed = char_cols * BRAILLE_HORIZONTAL_RESOLUTION;
+    let points_needed = char_cols * a.out.glyphs.cols();
     let dt = 1.0 / a.fps.max(1) as f64;
 
     for i in 0..points_needed.min(a.steps) {
@@ -110,14 +606,23 @@ pub fn demo(a: &DemoArgs) -> Result<(), GraphError> {
         let (y_lo, y_hi) = Axis::Y.bounds(&data);
 
         // Determine label width **now** (exact, not guessed)
-        let lbl_w = bounds::y_label_width(y_lo, y_hi, DECIMAL_PRECISION);
+        let lbl_w = bounds::y_label_width(y_lo, y_hi, Scale::Linear, DECIMAL_PRECISION);
 
         // Terminal geometry – recalc every frame (handles resizes)
         let term = bounds::terminal_geometry();
-        let cols_av = term.0.0 as usize - BORDER_WIDTH - LABEL_GUTTER - lbl_w - 1;
+        let frame_w = BORDER_WIDTH + LABEL_GUTTER + lbl_w;
+        let cols_av = a.out.width.map_or(term.0.0 as usize - frame_w - 1, |w| {
+            w.plot_chars(a.out.glyphs.cols(), frame_w)
+        });
         let x_chars = cols_av.max(MIN_GRAPH_WIDTH);
-        let y_chars = (term.1.0 as usize).saturating_sub(5).max(MIN_GRAPH_HEIGHT);
-        let max_pts = x_chars * BRAILLE_HORIZONTAL_RESOLUTION;
+        let y_chars = a
+            .out
+            .height
+            .map_or((term.1.0 as usize).saturating_sub(5), |h| {
+                h.plot_chars(a.out.glyphs.rows(), FRAME_ROWS)
+            })
+            .max(MIN_GRAPH_HEIGHT);
+        let max_pts = x_chars * a.out.glyphs.cols();
 
         if a.scroll && data.len() > max_pts {
             data.drain(..data.len() - max_pts);
@@ -126,9 +631,12 @@ pub fn demo(a: &DemoArgs) -> Result<(), GraphError> {
         let cfg = Config::builder(x_chars, y_chars)
             .title("Itô Process Demo")
             .subtitle(format!("μ = {},  σ = {}", a.mu, a.sigma))
-            .color(a.color)
+            .color(a.out.fg)
+            .colored(a.out.color.enabled())
+            .x_format(TimeFormat::Duration)
             .y_range(y_lo..=y_hi)
             .x_range(data.first().unwrap().time, data.last().unwrap().time)
+            .glyphs(a.out.glyphs)
             .build()?;
 
         let setup_us = t0.elapsed().as_micros();
@@ -136,8 +644,8 @@ pub fn demo(a: &DemoArgs) -> Result<(), GraphError> {
 
         let t1 = Instant::now();
         // Apply optional binning
-        let binned = binner.bin(&data, &cfg);
-        let plot = preprocess_to_braille(&binned, &cfg, false)?;
+        let steps = binner.bin(&data, &cfg);
+        let plot = preprocess_to_braille(&steps, &cfg, false)?;
         let processing_us = t1.elapsed().as_micros();
         total_processing_us += processing_us;
 
@@ -193,9 +701,12 @@ pub fn examples() {
 Example invocations
 -------------------
 • Basic CSV        : {bin} csv sample_data/industrial_production.csv
+• Distribution    : {bin} hist sample_data/industrial_production.csv --bins fd
 • Connected plot  : {bin} csv sample_data/industrial_production.csv --bridge
-• Named color     : {bin} csv sample_data/industrial_production.csv --color blue
-• Hex color       : {bin} csv sample_data/industrial_production.csv --color #6048c1
+• Line plot       : {bin} csv sample_data/industrial_production.csv --style line
+• Sparkline       : {bin} sparkline sample_data/industrial_production.csv --width 30
+• Named color     : {bin} csv sample_data/industrial_production.csv --fg blue
+• Hex color       : {bin} csv sample_data/industrial_production.csv --fg #6048c1
 • Custom title     : {bin} csv sample_data/industrial_production.csv \\
                       --title \"American Industrial Production, Aug 1929 = 100\"
 • Debug mode       : {bin} csv sample_data/industrial_production.csv --debug
@@ -203,3 +714,92 @@ Example invocations
 "
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+    use crate::render::compose;
+
+    /// Three series stacked in `height` rows, as `csv --layout stack`.
+    fn stack(height: usize) -> Result<String, GraphError> {
+        let h = height.to_string();
+        let a = CsvArgs::parse_from([
+            "csv", "data.csv", "--layout", "stack", "--width", "72", "--height", &h,
+        ]);
+        let csv: String = std::iter::once("time,a,b,c\n".to_owned())
+            .chain((0..200).map(|i| format!("{i},{},{},{}\n", i % 7, i % 11, i * i)))
+            .collect();
+        let table = crate::core::data::read_csv_table(csv.as_bytes()).unwrap();
+        let series = table.all_series();
+        let cfgs = panel_configs(&a, Layout::Stack, &series, table.time_kind)?;
+        let mut plots = Vec::new();
+        for (s, cfg) in series.iter().zip(&cfgs) {
+            let mut binned = Vec::new();
+            bin_series(&a, s, &mut Binner::new(a.bin_type), cfg, &mut binned);
+            plots.push(preprocess_series(&binned, cfg, a.bridge)?);
+        }
+        let panels: Vec<_> = cfgs
+            .iter()
+            .zip(&plots)
+            .map(|(cfg, plot)| Panel { cfg, plot })
+            .collect();
+        compose(Layout::Stack, &panels)
+    }
+
+    #[test]
+    fn three_stacked_panels_fit_a_normal_terminal() {
+        for height in [24, 30] {
+            let frame = stack(height).unwrap();
+            let lines: Vec<_> = frame.lines().collect();
+            assert_eq!(lines.len(), height);
+            // one title bar per panel, one shared x axis and bottom bar
+            assert_eq!(lines.iter().filter(|l| l.starts_with('├')).count(), 2);
+            assert_eq!(lines.iter().filter(|l| l.starts_with('└')).count(), 1);
+        }
+    }
+
+    #[test]
+    fn follow_draws_one_frame_per_batch_of_rows() {
+        use std::{io::Write, sync::mpsc, thread, time::Duration};
+
+        use crate::core::data::follow_reader;
+
+        let (rd, mut wr) = std::io::pipe().unwrap();
+        let writer = thread::spawn(move || {
+            wr.write_all(b"time,v\n").unwrap();
+            for row in ["1,1\n", "2,4\n", "3,9\n"] {
+                wr.write_all(row.as_bytes()).unwrap();
+                thread::sleep(Duration::from_millis(100));
+            }
+        });
+
+        // Tail the pipe like a file, so it keeps polling after EOF, and hang
+        // up after 20 idle polls in a row.
+        let polled = follow_reader(rd, true, Duration::from_millis(5));
+        let (tx, lines) = mpsc::channel();
+        thread::spawn(move || {
+            let mut idle = 0;
+            while idle < 20 {
+                let Ok(line) = polled.recv() else { break };
+                idle = if matches!(&line, Ok(l) if l.is_empty()) {
+                    idle + 1
+                } else {
+                    0
+                };
+                tx.send(line).unwrap();
+            }
+        });
+
+        let a = CsvArgs::parse_from([
+            "csv", "-", "--follow", "--fps", "100", "--width", "40", "--height", "12",
+        ]);
+        let mut out = Vec::new();
+        follow(&a, &lines, Renderer::plain(), &mut out).unwrap();
+        writer.join().unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.lines().filter(|l| l.starts_with('┌')).count(), 3);
+    }
+}
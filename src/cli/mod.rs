@@ -1,15 +1,22 @@
 mod handlers;
 pub mod parse;
+mod view;
 
 use clap::Parser;
 pub use parse::Cli;
 
 use crate::core::error::GraphError;
 
+/// # Errors
+///
+/// Whatever the subcommand fails with: unreadable input, a bad
+/// configuration or a terminal write error.
 pub fn run() -> Result<(), GraphError> {
     let cli = parse::Cli::parse();
     match cli.cmd {
-        parse::Command::Csv(a) => handlers::csv(a),
+        parse::Command::Csv(a) => handlers::csv(&a),
+        parse::Command::Hist(a) => handlers::hist(&a),
+        parse::Command::Sparkline(a) => handlers::sparkline(&a),
         parse::Command::Colors => {
             handlers::colors();
             Ok(())
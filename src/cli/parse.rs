@@ -1,5 +1,14 @@
-use crate::{AnsiCode, Strategy};
-use clap::{Parser, Subcommand};
+use crate::{
+    Aggregate, AnsiCode, BinRule, ColorMode, Layout, Strategy,
+    core::{
+        bounds::Scale,
+        config::{Baseline, Extent, PlotStyle},
+        data::ColumnSpec,
+        glyphs::Glyphs,
+        time::{TimeFormat, parse_timestamp},
+    },
+};
+use clap::{Args, Parser, Subcommand};
 
 /// Top-level CLI structure.
 #[derive(Parser)]
@@ -15,7 +24,11 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Command {
     /// Plot data from a CSV file
-    Csv(CsvArgs),
+    Csv(Box<CsvArgs>),
+    /// Plot the distribution of a CSV column
+    Hist(HistArgs),
+    /// Print a CSV column as a bare, borderless sparkline
+    Sparkline(SparklineArgs),
     /// Show available color names / hex syntax
     Colors,
     /// Animated Brownian motion demo
@@ -25,7 +38,8 @@ pub enum Command {
 }
 
 /// `braille-graph csv …`
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)] // independent CLI switches
 pub struct CsvArgs {
     #[arg(
         value_name = "FILE",
@@ -34,6 +48,15 @@ pub struct CsvArgs {
     )]
     pub file: String,
 
+    #[arg(
+        short,
+        long = "column",
+        value_name = "SPEC",
+        value_delimiter = ',',
+        help = "Column(s) to plot: header name, 1-based field number or `min:max` pair; repeat or comma-separate to overlay (default: fields 2[:3], or every column if there are more)"
+    )]
+    pub columns: Vec<ColumnSpec>,
+
     #[arg(short, long, default_value = "CSV Data", help = "Graph title")]
     pub title: String,
 
@@ -45,22 +68,98 @@ pub struct CsvArgs {
     #[arg(long, help = "Y-axis upper bound (auto if omitted)")]
     pub y_max: Option<f64>,
 
-    #[arg(long, help = "X-axis lower bound (auto if omitted)")]
+    #[arg(long, default_value = "linear", value_parser = parse_scale, help = "Y-axis scale: linear, log (base 10, values > 0) or symlog[:T] (linear within ±T, default 1)")]
+    pub y_scale: Scale,
+
+    #[arg(long, value_parser = parse_time_value, help = "X-axis lower bound, number or timestamp (auto if omitted)")]
     pub x_min: Option<f64>,
-    #[arg(long, help = "X-axis upper bound (auto if omitted)")]
+    #[arg(long, value_parser = parse_time_value, help = "X-axis upper bound, number or timestamp (auto if omitted)")]
     pub x_max: Option<f64>,
 
-    #[arg(long, default_value = "industrial", value_parser = parse_ansi, help = "Color (name or `#RRGGBB`")]
-    pub color: AnsiCode,
+    #[command(flatten)]
+    pub out: OutputArgs,
+
+    #[arg(long, value_delimiter = ',', value_parser = parse_ansi, help = "Per-series colors, comma-separated (default: --fg, then a built-in palette)")]
+    pub colors: Vec<AnsiCode>,
+
+    #[arg(long, value_parser = parse_ansi, help = "Color for cells shared by several series (default: last series wins)")]
+    pub overlap_color: Option<AnsiCode>,
+
+    #[arg(long, help = "Draw faint horizontal gridlines at the y-axis ticks")]
+    pub grid: bool,
+
+    #[arg(long, help = "Hide the series legend")]
+    pub no_legend: bool,
 
     #[arg(long, help = "Bridge min/max envelopes")]
     pub bridge: bool,
 
+    #[arg(long, default_value = "envelope", value_parser = parse_style, help = "Draw each series as its min/max envelope, as a line joining the points (placed by time), or as an area filled to --baseline, or as one dot per row (scatter, unbinned)")]
+    pub style: PlotStyle,
+
+    #[arg(long, value_parser = parse_layout, help = "One panel per column, each with its own y axis: stack (one above the other), row (side by side) or grid:N (N per row)")]
+    pub layout: Option<Layout>,
+
+    #[arg(long, default_value = "zero", value_parser = parse_baseline, help = "With --style area: fill to zero, min (bottom of the plot) or a value")]
+    pub baseline: Baseline,
+
+    #[arg(long, value_parser = parse_ansi, help = "With --style area: color of the area below the baseline (default: series color)")]
+    pub below_color: Option<AnsiCode>,
+
+    #[arg(
+        long,
+        help = "With --style scatter: color cells by how many points they hold"
+    )]
+    pub density: bool,
+
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Break the line where rows are more than N times the median spacing apart"
+    )]
+    pub gap: Option<f64>,
+
     #[arg(long, help = "Emit timing diagnostics")]
     pub debug: bool,
 
-    #[arg(long, default_value = "time", value_parser = parse_strategy, help = "Choose whether to bin the x_axis by index or time")]
+    #[arg(
+        short,
+        long,
+        help = "Keep reading as rows are appended (like `tail -f`) and redraw live"
+    )]
+    pub follow: bool,
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["follow", "layout"],
+        help = "Explore the plot: arrows pan, +/- zoom, y toggles y autoscale, r resets, q quits"
+    )]
+    pub interactive: bool,
+    #[arg(
+        long,
+        value_name = "ROWS",
+        requires = "follow",
+        help = "With --follow: plot only the newest ROWS rows (default: bin the full history)"
+    )]
+    pub window: Option<usize>,
+    #[arg(
+        long,
+        requires = "follow",
+        conflicts_with = "window",
+        help = "With --follow: scroll once the data exceeds the plot width"
+    )]
+    pub scroll: bool,
+    #[arg(long, default_value_t = 10, help = "With --follow: redraws per second")]
+    pub fps: u64,
+
+    #[arg(long, default_value = "time", value_parser = parse_strategy, help = "Bin the x axis by index or time (min/max envelope), or lttb (shape-preserving line)")]
     pub bin_type: Strategy,
+
+    #[arg(long, default_value = "extrema", value_parser = parse_aggregate, help = "Reduce each bucket to: extrema (min/max), mean (± stddev), median (p5–p95 band) or pLO-pHI (median, custom band); ignored by lttb")]
+    pub aggregate: Aggregate,
+
+    #[arg(long, value_parser = parse_time_format, help = "X-axis labels: number, duration (seconds), date or datetime (epoch seconds); detected from the time column if omitted")]
+    pub x_format: Option<TimeFormat>,
 }
 
 /// `braille-graph demo …`
@@ -86,8 +185,9 @@ pub struct DemoArgs {
     pub sigma: f64,
     #[arg(long, default_value_t = 60, help = "Updates per second")]
     pub fps: u64,
-    #[arg(long, default_value = "industrial", value_parser = parse_ansi, help = "Use colors command for valid strings")]
-    pub color: AnsiCode,
+    #[command(flatten)]
+    pub out: OutputArgs,
+
     #[arg(
         long,
         default_value_t = false,
@@ -98,6 +198,108 @@ pub struct DemoArgs {
     pub debug: bool,
 }
 
+/// `braille-graph hist …`
+#[derive(Parser, Debug)]
+pub struct HistArgs {
+    #[arg(
+        value_name = "FILE",
+        default_value = "-",
+        help = "CSV path (use `-` for stdin)"
+    )]
+    pub file: String,
+
+    #[arg(
+        short,
+        long,
+        value_name = "SPEC",
+        help = "Column to count: header name or 1-based field number; a `min:max` pair counts both (default: field 2)"
+    )]
+    pub column: Option<ColumnSpec>,
+
+    #[arg(short, long, help = "Graph title (default: the column name)")]
+    pub title: Option<String>,
+
+    #[arg(short, long, help = "Subtitle (default: summary statistics)")]
+    pub subtitle: Option<String>,
+
+    #[arg(long, default_value = "sturges", value_parser = parse_bin_rule, help = "Bucket count N, or a rule: sturges or fd (Freedman–Diaconis)")]
+    pub bins: BinRule,
+
+    #[arg(long, help = "Bar heights are log10(1 + count)")]
+    pub log: bool,
+
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "50,90,99",
+        help = "Percentiles shown in the summary subtitle, comma-separated"
+    )]
+    pub percentiles: Vec<f64>,
+
+    #[command(flatten)]
+    pub out: OutputArgs,
+}
+
+/// `braille-graph sparkline …`
+#[derive(Parser, Debug)]
+#[command(
+    mut_arg("width", |a| a.help("Width: characters, or dots with a `d` suffix (e.g. 40 or 80d) [default: 20]")),
+    mut_arg("height", |a| a.help("Height: characters (rows), or dots with a `d` suffix (e.g. 2 or 8d) [default: 1]")),
+)]
+pub struct SparklineArgs {
+    #[arg(
+        value_name = "FILE",
+        default_value = "-",
+        help = "CSV path (use `-` for stdin)"
+    )]
+    pub file: String,
+
+    #[arg(
+        short,
+        long,
+        value_name = "SPEC",
+        help = "Column to plot: header name or 1-based field number, or a `min:max` pair (default: field 2)"
+    )]
+    pub column: Option<ColumnSpec>,
+
+    #[arg(long, help = "Y-axis lower bound (auto if omitted)")]
+    pub y_min: Option<f64>,
+    #[arg(long, help = "Y-axis upper bound (auto if omitted)")]
+    pub y_max: Option<f64>,
+
+    #[arg(long, default_value = "line", value_parser = parse_style, help = "envelope, line, area or scatter (see `csv --help`)")]
+    pub style: PlotStyle,
+
+    #[command(flatten)]
+    pub out: OutputArgs,
+}
+
+/// Color, glyph and size options shared by every plotting command.
+#[derive(Args, Debug, Clone)]
+pub struct OutputArgs {
+    #[arg(long, value_name = "COLOR", default_value = "industrial", value_parser = parse_ansi, help = "Plot color (name or `#RRGGBB`, see the colors command)")]
+    pub fg: AnsiCode,
+
+    #[arg(long, alias = "color-mode", value_name = "WHEN", default_value = "auto", value_parser = parse_color_mode, help = "Emit colors: always, auto or never.  auto drops them when stdout is not a terminal or NO_COLOR is set; NO_COLOR only removes colors, while a non-terminal stdout also gets one plain frame without cursor movement")]
+    pub color: ColorMode,
+
+    #[arg(long, default_value = "braille", value_parser = parse_glyphs, help = "Plot characters: braille (2×4 dots), block (1×2), quadrant (2×2), sextant (2×3) or ascii")]
+    pub glyphs: Glyphs,
+
+    #[arg(
+        long,
+        value_name = "SIZE",
+        help = "Frame width: characters incl. borders and labels, or plot dots with a `d` suffix (e.g. 100 or 160d); default: terminal width"
+    )]
+    pub width: Option<Extent>,
+    #[arg(
+        long,
+        value_name = "SIZE",
+        help = "Frame height: characters incl. title and label rows, or plot dots with a `d` suffix (e.g. 30 or 80d); default: terminal height"
+    )]
+    pub height: Option<Extent>,
+}
+
 fn parse_ansi(s: &str) -> Result<AnsiCode, String> {
     match s.to_ascii_lowercase().as_str() {
         // accepted names
@@ -116,10 +318,138 @@ fn parse_ansi(s: &str) -> Result<AnsiCode, String> {
     }
 }
 
+fn parse_color_mode(s: &str) -> Result<ColorMode, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "always" => Ok(ColorMode::Always),
+        "auto" => Ok(ColorMode::Auto),
+        "never" => Ok(ColorMode::Never),
+        _ => Err(format!(
+            "unknown color mode '{s}' (try always, auto or never)"
+        )),
+    }
+}
+
 fn parse_strategy(s: &str) -> Result<Strategy, String> {
     match s.to_ascii_lowercase().as_str() {
         "index" => Ok(Strategy::Index),
         "time" => Ok(Strategy::Time),
-        _ => Err(format!("unknown bin type '{s}' (try index or time)")),
+        "lttb" => Ok(Strategy::Lttb),
+        _ => Err(format!("unknown bin type '{s}' (try index, time or lttb)")),
+    }
+}
+
+fn parse_style(s: &str) -> Result<PlotStyle, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "envelope" => Ok(PlotStyle::Envelope),
+        "line" => Ok(PlotStyle::Line),
+        "area" => Ok(PlotStyle::Area(Baseline::Zero)),
+        "scatter" => Ok(PlotStyle::Scatter { density: false }),
+        _ => Err(format!(
+            "unknown style '{s}' (try envelope, line, area or scatter)"
+        )),
+    }
+}
+
+fn parse_glyphs(s: &str) -> Result<Glyphs, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "braille" => Ok(Glyphs::Braille),
+        "block" | "halfblock" => Ok(Glyphs::HalfBlock),
+        "quadrant" => Ok(Glyphs::Quadrant),
+        "sextant" => Ok(Glyphs::Sextant),
+        "ascii" => Ok(Glyphs::Ascii),
+        _ => Err(format!(
+            "unknown glyphs '{s}' (try braille, block, quadrant, sextant or ascii)"
+        )),
+    }
+}
+
+fn parse_layout(s: &str) -> Result<Layout, String> {
+    let lower = s.to_ascii_lowercase();
+    match lower.split_once(':').unwrap_or((&lower, "")) {
+        ("stack", "") => Ok(Layout::Stack),
+        ("row", "") => Ok(Layout::Row),
+        ("grid", n) => match n.parse() {
+            Ok(cols) if cols > 0 => Ok(Layout::Grid { cols }),
+            _ => Err(format!("invalid grid '{s}' (try grid:2)")),
+        },
+        _ => Err(format!("unknown layout '{s}' (try stack, row or grid:2)")),
+    }
+}
+
+fn parse_bin_rule(s: &str) -> Result<BinRule, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "sturges" => Ok(BinRule::Sturges),
+        "fd" | "freedman-diaconis" => Ok(BinRule::FreedmanDiaconis),
+        n => match n.parse() {
+            Ok(k) if k > 0 => Ok(BinRule::Count(k)),
+            _ => Err(format!("invalid bins '{s}' (try 20, sturges or fd)")),
+        },
     }
 }
+
+fn parse_scale(s: &str) -> Result<Scale, String> {
+    let lower = s.to_ascii_lowercase();
+    let (name, arg) = lower.split_once(':').unwrap_or((&lower, ""));
+    match (name, arg) {
+        ("linear", "") => Ok(Scale::Linear),
+        ("log" | "log10", "") => Ok(Scale::Log10),
+        ("symlog", "") => Ok(Scale::SymLog { threshold: 1.0 }),
+        ("symlog", t) => t
+            .parse()
+            .map(|threshold| Scale::SymLog { threshold })
+            .map_err(|_| format!("invalid symlog threshold '{t}'")),
+        _ => Err(format!("unknown scale '{s}' (try linear, log or symlog:1)")),
+    }
+}
+
+fn parse_baseline(s: &str) -> Result<Baseline, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "zero" => Ok(Baseline::Zero),
+        "min" => Ok(Baseline::YMin),
+        v => v
+            .parse()
+            .map(Baseline::Value)
+            .map_err(|_| format!("invalid baseline '{s}' (try zero, min or a number)")),
+    }
+}
+
+fn parse_aggregate(s: &str) -> Result<Aggregate, String> {
+    let s = s.to_ascii_lowercase();
+    match s.as_str() {
+        "extrema" | "minmax" => return Ok(Aggregate::Extrema),
+        "mean" => return Ok(Aggregate::MeanStd),
+        "median" => return Ok(Aggregate::Percentile { lo: 5.0, hi: 95.0 }),
+        _ => {}
+    }
+    let pct = |p: &str| {
+        p.trim_start_matches('p')
+            .parse::<f64>()
+            .ok()
+            .filter(|v| (0.0..=100.0).contains(v))
+    };
+    s.split_once('-')
+        .and_then(|(lo, hi)| Some((pct(lo)?, pct(hi)?)))
+        .filter(|(lo, hi)| lo < hi)
+        .map(|(lo, hi)| Aggregate::Percentile { lo, hi })
+        .ok_or_else(|| format!("unknown aggregate '{s}' (try extrema, mean, median or p10-p90)"))
+}
+
+fn parse_time_format(s: &str) -> Result<TimeFormat, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "number" => Ok(TimeFormat::Number),
+        "duration" => Ok(TimeFormat::Duration),
+        "date" => Ok(TimeFormat::Date),
+        "datetime" => Ok(TimeFormat::DateTime),
+        _ => Err(format!(
+            "unknown x format '{s}' (try number, duration, date or datetime)"
+        )),
+    }
+}
+
+fn parse_time_value(s: &str) -> Result<f64, String> {
+    s.trim()
+        .parse::<f64>()
+        .ok()
+        .or_else(|| parse_timestamp(s.trim().as_bytes()).map(|(t, _)| t))
+        .ok_or_else(|| format!("'{s}' is neither a number nor an ISO-8601 timestamp"))
+}
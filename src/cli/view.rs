@@ -0,0 +1,415 @@
+//! `csv --interactive`: pan and zoom through a CSV from the keyboard, and
+//! read exact values off a cursor moved by keys or mouse.
+
+use crate::{
+    core::{
+        bounds::{Axis, Scale, terminal_geometry},
+        color::AnsiCode,
+        config::{Config, PlotStyle},
+        constants::{DECIMAL_PRECISION, LABEL_GUTTER},
+        data::{DataTimeStep, Series},
+        error::GraphError,
+        time::TimeFormat,
+    },
+    render::{
+        Binner, Renderer, Source,
+        braille::{BraillePlot, GraphTimeStep, Layer},
+        preprocess_series,
+        term::{Key, RawTerminal},
+    },
+};
+
+use super::{
+    handlers::{bin_series, csv_config, label_span},
+    parse::CsvArgs,
+};
+
+/// Share of the visible range one arrow key moves it by.
+const PAN_STEP: f64 = 0.1;
+/// Smallest visible share of the full time range.
+const MAX_ZOOM: f64 = 1e-4;
+/// Shown as subtitle unless `--subtitle` is given.
+const HELP: &str = "←→↑↓ pan  +/- zoom  y autoscale  c cursor  r reset  q quit";
+/// Color of the cursor column.
+const CURSOR_COLOR: AnsiCode = AnsiCode::white();
+
+/// What is on screen, kept as the `--x-min`/`--x-max` (and, with autoscale
+/// off, `--y-min`/`--y-max`) of a copy of the arguments so every redraw
+/// goes through the same path as a plain `csv` run.
+struct View<'a> {
+    args: CsvArgs,
+    initial: &'a CsvArgs,
+    series: &'a [Series],
+    time_kind: TimeFormat,
+    /// Time range of the whole data set.
+    full: (f64, f64),
+    autoscale: bool,
+    /// Dot column of the cursor, when shown.
+    cursor: Option<usize>,
+}
+
+impl<'a> View<'a> {
+    fn new(a: &'a CsvArgs, series: &'a [Series], time_kind: TimeFormat) -> Self {
+        let full = Axis::X.bounds(series.iter().flat_map(|s| &s.steps));
+        let mut view = Self {
+            args: a.clone(),
+            initial: a,
+            series,
+            time_kind,
+            full,
+            autoscale: true,
+            cursor: None,
+        };
+        view.set_x(a.x_min.unwrap_or(full.0), a.x_max.unwrap_or(full.1));
+        view.label();
+        view
+    }
+
+    fn x(&self) -> (f64, f64) {
+        (
+            self.args.x_min.unwrap_or(self.full.0),
+            self.args.x_max.unwrap_or(self.full.1),
+        )
+    }
+
+    /// Show `lo..=hi`, shifted (not shrunk) to stay inside the data.
+    fn set_x(&mut self, lo: f64, hi: f64) {
+        let (min, max) = self.full;
+        let span = (hi - lo).min(max - min);
+        // `max - span` can round to just below `min`
+        let lo = lo.clamp(min, (max - span).max(min));
+        self.args.x_min = Some(lo);
+        self.args.x_max = Some(lo + span);
+    }
+
+    fn pan(&mut self, share: f64) {
+        let (lo, hi) = self.x();
+        let d = (hi - lo) * share;
+        self.set_x(lo + d, hi + d);
+    }
+
+    /// Scale the visible time span by `factor` around its centre.
+    fn zoom(&mut self, factor: f64) {
+        let (lo, hi) = self.x();
+        let full = self.full.1 - self.full.0;
+        let half = ((hi - lo) * factor).clamp(full * MAX_ZOOM, full) / 2.0;
+        let mid = f64::midpoint(lo, hi);
+        self.set_x(mid - half, mid + half);
+    }
+
+    /// Pin the y axis to what is shown now, or fit it to the visible data
+    /// again.
+    fn toggle_autoscale(&mut self) -> Result<(), GraphError> {
+        if self.autoscale {
+            self.freeze_y()?;
+        } else {
+            self.autoscale = true;
+            self.args.y_min = self.initial.y_min;
+            self.args.y_max = self.initial.y_max;
+        }
+        self.label();
+        Ok(())
+    }
+
+    fn freeze_y(&mut self) -> Result<(), GraphError> {
+        let cfg = csv_config(&self.args, self.series, self.time_kind, true)?;
+        self.args.y_min = Some(cfg.y_min);
+        self.args.y_max = Some(cfg.y_max);
+        self.autoscale = false;
+        Ok(())
+    }
+
+    /// Move the y axis by `share` of its span; turns autoscale off.
+    fn pan_y(&mut self, share: f64) -> Result<(), GraphError> {
+        if self.autoscale {
+            self.freeze_y()?;
+            self.label();
+        }
+        let (Some(lo), Some(hi)) = (self.args.y_min, self.args.y_max) else {
+            return Ok(());
+        };
+        let d = (hi - lo) * share;
+        if !matches!(self.args.y_scale, Scale::Log10) || lo + d > 0.0 {
+            self.args.y_min = Some(lo + d);
+            self.args.y_max = Some(hi + d);
+        }
+        Ok(())
+    }
+
+    /// Move the cursor by `d` dot columns, panning when it is pushed past
+    /// either edge of the `cols` shown.
+    fn move_cursor(&mut self, d: isize, cols: usize) {
+        let Some(c) = self.cursor else { return };
+        match c.checked_add_signed(d) {
+            Some(c) if c < cols => self.cursor = Some(c),
+            _ => self.pan(if d < 0 { -PAN_STEP } else { PAN_STEP }),
+        }
+    }
+
+    fn label(&mut self) {
+        if self.initial.subtitle.is_none() {
+            let y = if self.autoscale { "auto" } else { "fixed" };
+            self.args.subtitle = Some(format!("{HELP}  (y: {y})"));
+        }
+    }
+}
+
+/// Bin and render what is on screen, with the cursor column and its
+/// readout in the bottom bar when the cursor is shown.  Returns the config
+/// drawn with, for mapping mouse clicks.
+fn redraw(
+    view: &mut View<'_>,
+    binners: &mut [Binner],
+    renderer: &mut Renderer,
+) -> Result<Config, GraphError> {
+    let a = &view.args;
+    let mut cfg = csv_config(a, view.series, view.time_kind, true)?;
+    let mut binned = Vec::with_capacity(view.series.len());
+    let mut bands = Vec::with_capacity(view.series.len());
+    for (s, b) in view.series.iter().zip(binners.iter_mut()) {
+        bands.push(binned.len());
+        bin_series(a, s, b, &cfg, &mut binned);
+    }
+    let mut plot = preprocess_series(&binned, &cfg, a.bridge)?;
+    label_span(a, &mut plot, binners);
+
+    if let Some(c) = view.cursor {
+        let c = c.min(cfg.dot_columns().saturating_sub(1));
+        view.cursor = Some(c);
+        let mut steps = vec![None; c + 1];
+        steps[c] = Some(GraphTimeStep {
+            min: 0,
+            max: cfg.dot_rows().saturating_sub(1),
+        });
+        let picks: Vec<_> = view
+            .series
+            .iter()
+            .zip(binners.iter())
+            .zip(&bands)
+            .map(|((s, b), &k)| pick(a.style, c, &cfg, &plot, s, &binned[k].steps, b))
+            .collect();
+        plot.layers.push(Layer {
+            name: String::new(),
+            color: CURSOR_COLOR,
+            steps,
+            scatter: None,
+        });
+        if view.initial.subtitle.is_none() {
+            cfg.subtitle = Some(view.readout(&picks));
+        }
+    }
+    renderer.render(&cfg, &plot)?;
+    Ok(cfg)
+}
+
+/// The raw samples of `s` behind dot column `c`, or `None` for a blank
+/// column.  `drawn` is what `binner` made of `s` for this frame.
+#[allow(clippy::cast_precision_loss)] // dot columns are far below 2^52
+fn pick(
+    style: PlotStyle,
+    c: usize,
+    cfg: &Config,
+    plot: &BraillePlot,
+    s: &Series,
+    drawn: &[DataTimeStep],
+    binner: &Binner,
+) -> Option<Source> {
+    // step closest in time to the cursor, for styles that place steps by
+    // time (scatter data need not be sorted)
+    let nearest = |steps: &[DataTimeStep]| {
+        let (lo, hi) = plot.x_span?;
+        let t = lo + (hi - lo) * c as f64 / cfg.dot_columns().saturating_sub(1).max(1) as f64;
+        steps
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.min.is_finite() && p.max.is_finite())
+            .min_by(|(_, p), (_, q)| (p.time - t).abs().total_cmp(&(q.time - t).abs()))
+            .map(|(i, _)| i)
+    };
+    match style {
+        PlotStyle::Scatter { .. } => nearest(&s.steps).map(|i| Source {
+            start: i,
+            end: i + 1,
+            min_index: i,
+            max_index: i,
+        }),
+        PlotStyle::Line => nearest(drawn).and_then(|i| binner.sources().get(i).copied()),
+        _ => binner.sources().get(c).copied(),
+    }
+    .filter(|src| src.start < src.end)
+}
+
+impl View<'_> {
+    /// `t=<time>  <series> <min>…<max>  …` for the samples under the
+    /// cursor, with a time range when a bucket spans several samples.
+    fn readout(&self, picks: &[Option<Source>]) -> String {
+        let fmt = self.args.x_format.unwrap_or(self.time_kind);
+        let time = self.series.iter().zip(picks).find_map(|(s, src)| {
+            let src = src.as_ref()?;
+            let (t0, t1) = (s.steps[src.start].time, s.steps[src.end - 1].time);
+            Some(if t1 > t0 {
+                format!("{}…{}", fmt.exact(t0), fmt.exact(t1))
+            } else {
+                fmt.exact(t0)
+            })
+        });
+        let mut out = format!("t={}", time.as_deref().unwrap_or("–"));
+        for (s, src) in self.series.iter().zip(picks) {
+            let value = src.map_or_else(
+                || "–".to_owned(),
+                |src| {
+                    let lo = format!("{:.DECIMAL_PRECISION$}", s.steps[src.min_index].min);
+                    let hi = format!("{:.DECIMAL_PRECISION$}", s.steps[src.max_index].max);
+                    if lo == hi { lo } else { format!("{lo}…{hi}") }
+                },
+            );
+            out.push_str("  ");
+            if !s.name.is_empty() {
+                out.push_str(&s.name);
+                out.push(' ');
+            }
+            out.push_str(&value);
+        }
+        out
+    }
+}
+
+/// Redraw after every key press until `q`, Esc or Ctrl-C.
+pub(super) fn explore(
+    a: &CsvArgs,
+    series: &[Series],
+    time_kind: TimeFormat,
+) -> Result<(), GraphError> {
+    if series.iter().all(|s| s.steps.is_empty()) {
+        return Err(GraphError::EmptyData);
+    }
+    let mut view = View::new(a, series, time_kind);
+    let mut binners: Vec<_> = series
+        .iter()
+        .map(|_| Binner::new(a.bin_type).aggregate(a.aggregate))
+        .collect();
+
+    let mut term = RawTerminal::enter()?;
+    term.capture_mouse()?;
+    // the raw terminal keeps the cursor hidden for the whole session
+    let mut renderer = Renderer::delta().hide_cursor(false);
+    let mut size = terminal_geometry();
+    let mut cfg = redraw(&mut view, &mut binners, &mut renderer)?;
+    let mut dirty = false;
+    loop {
+        if dirty {
+            cfg = redraw(&mut view, &mut binners, &mut renderer)?;
+            dirty = false;
+        }
+        let cols = cfg.dot_columns();
+        for key in term.read_keys()? {
+            match key {
+                Key::Char('q' | '\u{3}') | Key::Esc => return Ok(()),
+                Key::Left if view.cursor.is_some() => view.move_cursor(-1, cols),
+                Key::Right if view.cursor.is_some() => view.move_cursor(1, cols),
+                Key::Left => view.pan(-PAN_STEP),
+                Key::Right => view.pan(PAN_STEP),
+                Key::Up => view.pan_y(PAN_STEP)?,
+                Key::Down => view.pan_y(-PAN_STEP)?,
+                Key::Char('+' | '=') => view.zoom(0.5),
+                Key::Char('-' | '_') => view.zoom(2.0),
+                Key::Char('y') => view.toggle_autoscale()?,
+                Key::Char('c') => view.cursor = view.cursor.xor(Some(cols / 2)),
+                Key::Char('r') => view = View::new(a, series, time_kind),
+                Key::Mouse { col, row } => match plot_cell(&cfg, col, row) {
+                    Some(x) => view.cursor = Some(x * cfg.glyphs.cols()),
+                    None => continue,
+                },
+                Key::Char(_) => continue,
+            }
+            dirty = true;
+        }
+        // start over on a clean screen when the terminal is resized
+        let now = terminal_geometry();
+        if now != size {
+            size = now;
+            renderer = Renderer::delta().hide_cursor(false);
+            dirty = true;
+        }
+    }
+}
+
+/// Plot character column under screen cell `col`,`row` (0-based), for a
+/// frame drawn from the top left: border, y labels and gutter to the left,
+/// title and padding rows above.
+fn plot_cell(cfg: &Config, col: usize, row: usize) -> Option<usize> {
+    let x = col.checked_sub(1 + cfg.label_width() + LABEL_GUTTER)?;
+    (x < cfg.x_chars && (2..2 + cfg.y_chars).contains(&row)).then_some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    /// Pick and read out the samples under dot column `c`.
+    fn read_at(style: &str, c: impl Fn(&Config) -> usize) -> (Source, String) {
+        let a = CsvArgs::parse_from(["csv", "data.csv", "--style", style]);
+        let series = [Series {
+            name: "v".to_owned(),
+            steps: (0..100u32)
+                .map(|i| DataTimeStep {
+                    time: f64::from(i),
+                    min: f64::from(2 * i),
+                    max: f64::from(2 * i),
+                })
+                .collect(),
+            breaks: Vec::new(),
+        }];
+        let cfg = Config::builder(10, 10)
+            .y_range(0.0..=200.0)
+            .x_range(0.0, 99.0)
+            .style(a.style)
+            .build()
+            .unwrap();
+        let mut binner = Binner::new(a.bin_type);
+        let mut drawn = Vec::new();
+        bin_series(&a, &series[0], &mut binner, &cfg, &mut drawn);
+        let mut plot = preprocess_series(&drawn, &cfg, false).unwrap();
+        label_span(&a, &mut plot, std::slice::from_ref(&binner));
+
+        let c = c(&cfg);
+        let src = pick(
+            a.style,
+            c,
+            &cfg,
+            &plot,
+            &series[0],
+            &drawn[0].steps,
+            &binner,
+        )
+        .unwrap_or_else(|| panic!("{style}: nothing under column {c}"));
+        let view = View::new(&a, &series, TimeFormat::Number);
+        (src, view.readout(&[Some(src)]))
+    }
+
+    #[test]
+    fn cursor_in_first_column_reads_first_sample() {
+        for style in ["envelope", "line", "area", "scatter"] {
+            let (src, text) = read_at(style, |_| 0);
+            assert_eq!(src.start, 0, "{style}");
+            assert_eq!(src.min_index, 0, "{style}");
+            assert!(text.starts_with("t=0"), "{style}: {text}");
+            let value = format!(" v {:.DECIMAL_PRECISION$}", 0.0);
+            assert!(text.contains(&value), "{style}: {text}");
+        }
+    }
+
+    #[test]
+    fn cursor_in_last_column_reads_last_sample() {
+        for style in ["envelope", "line", "area", "scatter"] {
+            let (src, text) = read_at(style, |cfg| cfg.dot_columns() - 1);
+            assert_eq!(src.end, 100, "{style}");
+            assert_eq!(src.max_index, 99, "{style}");
+            assert!(text.contains("99  v"), "{style}: {text}");
+            let value = format!("{:.DECIMAL_PRECISION$}", 198.0);
+            assert!(text.ends_with(&value), "{style}: {text}");
+        }
+    }
+}
@@ -3,12 +3,37 @@
 use terminal_size::{Height, Width, terminal_size};
 
 use crate::core::{
-    constants::{
-        BORDER_WIDTH, BRAILLE_HORIZONTAL_RESOLUTION as HR, LABEL_GUTTER, MIN_GRAPH_HEIGHT,
-    },
+    constants::{BORDER_WIDTH, LABEL_GUTTER, MIN_GRAPH_HEIGHT, MIN_Y_TICKS, Y_TICK_TARGET},
     data::DataTimeStep,
+    glyphs::Glyphs,
 };
 
+/// How values map onto the y axis.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Scale {
+    #[default]
+    Linear,
+    /// Every decade is equally tall; all values must be positive.
+    Log10,
+    /// Linear within ±`threshold` of zero, logarithmic beyond; handles zero
+    /// and negative values.
+    SymLog { threshold: f64 },
+}
+
+impl Scale {
+    /// Position of `v` along the axis, up to an affine transform.  Values a
+    /// log scale can't show come out non-finite.
+    #[inline]
+    #[must_use]
+    pub fn apply(self, v: f64) -> f64 {
+        match self {
+            Self::Linear => v,
+            Self::Log10 => v.log10(),
+            Self::SymLog { threshold } => v.signum() * (v.abs() / threshold).ln_1p(),
+        }
+    }
+}
+
 /// Which axis we’re measuring.
 pub enum Axis {
     X,
@@ -22,8 +47,25 @@ impl Axis {
     ///   fallback is `(0.0, 1.0)`.
     /// * If *all* finite points are identical we expand by +-0.5 so the graph
     ///   still has non-zero height/width.
+    ///
+    /// Accepts any iterator of steps, so several series can share one range:
+    /// `Axis::Y.bounds(series.iter().flat_map(|s| &s.steps))`.
+    #[must_use]
+    pub fn bounds<'a>(self, steps: impl IntoIterator<Item = &'a DataTimeStep>) -> (f64, f64) {
+        self.bounds_in(steps, Scale::Linear)
+    }
+
+    /// [`bounds`](Self::bounds) for an axis drawn with `scale`: a flat
+    /// series on a log axis is widened by a factor of 2 each way (the ±0.5
+    /// rule could cross zero).  Non-positive values are kept, for
+    /// [`ConfigBuilder::build`](crate::core::config::ConfigBuilder::build)
+    /// to reject.
     #[must_use]
-    pub fn bounds(self, steps: &[DataTimeStep]) -> (f64, f64) {
+    pub fn bounds_in<'a>(
+        self,
+        steps: impl IntoIterator<Item = &'a DataTimeStep>,
+        scale: Scale,
+    ) -> (f64, f64) {
         let (mut low, mut high) = (f64::INFINITY, f64::NEG_INFINITY);
 
         for s in steps {
@@ -46,7 +88,10 @@ impl Axis {
 
         // Degenerate (flat-line) series - give it some breathing room.
         if (high - low).abs() < f64::EPSILON {
-            return (low - 0.5, high + 0.5);
+            return match scale {
+                Scale::Log10 if low > 0.0 => (low / 2.0, high * 2.0),
+                _ => (low - 0.5, high + 0.5),
+            };
         }
 
         // Normal case: exact extrema, no additional padding.
@@ -61,27 +106,211 @@ pub fn terminal_geometry() -> (Width, Height) {
     terminal_size().unwrap_or((Width(80), Height(30)))
 }
 
-/// Convert terminal dimensions + sample count to graph char grid.
-/// Leaves space for borders + labels.
+/// Convert terminal dimensions + sample count to graph char grid, at
+/// `glyphs.cols()` samples per character.  Leaves space for borders + labels.
 #[inline]
 #[must_use]
-pub fn graph_dims((w, h): (Width, Height), samples: usize) -> (usize, usize) {
+pub fn graph_dims((w, h): (Width, Height), samples: usize, glyphs: Glyphs) -> (usize, usize) {
     let x_chars = std::cmp::min(
-        (samples + HR - 1) / HR,
+        samples.div_ceil(glyphs.cols()),
         (w.0 as usize).saturating_sub(BORDER_WIDTH + LABEL_GUTTER + 1),
     );
     let y_chars = std::cmp::max(MIN_GRAPH_HEIGHT, usize::from(h.0).saturating_sub(5));
     (x_chars, y_chars)
 }
 
-/// How wide will the y-axis labels be for *current* min/max?
+/// How wide will the y-axis labels be for *current* min/max and the
+/// intermediate [`y_ticks`] between them?
 #[inline]
-pub fn y_label_width(y_min: f64, y_max: f64, decimals: usize) -> usize {
-    use std::fmt::Write;
-    let mut s = String::new();
-    write!(&mut s, "{y_min:.decimals$}").unwrap();
-    let lo = s.len();
-    s.clear();
-    write!(&mut s, "{y_max:.decimals$}").unwrap();
-    lo.max(s.len())
+#[must_use]
+pub fn y_label_width(y_min: f64, y_max: f64, scale: Scale, decimals: usize) -> usize {
+    [y_min, y_max]
+        .into_iter()
+        .chain(y_ticks(y_min, y_max, scale))
+        .map(|v| y_label(v, decimals).len())
+        .max()
+        .unwrap_or(0)
+}
+
+/// `v` with `decimals` places, or like `1e-4` when that would round a
+/// non-zero value to zero (common on log axes).
+#[must_use]
+pub fn y_label(v: f64, decimals: usize) -> String {
+    let fixed = format!("{v:.decimals$}");
+    if v != 0.0
+        && fixed
+            .trim_start_matches('-')
+            .bytes()
+            .all(|b| b == b'0' || b == b'.')
+    {
+        format!("{v:.0e}")
+    } else {
+        fixed
+    }
+}
+
+/// Round-valued ticks strictly between `y_min` and `y_max` (the extremes are
+/// always labelled on the first and last rows), at least [`MIN_Y_TICKS`] and
+/// about [`Y_TICK_TARGET`] of them: multiples of 1, 2 or 5 × 10ⁿ on a linear
+/// axis; powers of ten (and zero) on log axes, or 1 and 3 or 1, 2 and 5 ×
+/// 10ⁿ on log axes too short for enough powers.
+#[must_use]
+pub fn y_ticks(y_min: f64, y_max: f64, scale: Scale) -> Vec<f64> {
+    let inside = |t: f64| t > y_min && t < y_max;
+    let stages: [&[f64]; 3] = [&[1.0], &[1.0, 3.0], &[1.0, 2.0, 5.0]];
+    for mantissas in stages {
+        let (neg, zero, pos) = match scale {
+            Scale::Linear => break,
+            Scale::Log10 => (
+                Vec::new(),
+                false,
+                decade_ticks(y_min.max(f64::MIN_POSITIVE), y_max, mantissas),
+            ),
+            Scale::SymLog { threshold } => (
+                decade_ticks(threshold, -y_min, mantissas),
+                inside(0.0),
+                decade_ticks(threshold, y_max, mantissas),
+            ),
+        };
+        let neg: Vec<f64> = neg.into_iter().map(|p| -p).filter(|&t| inside(t)).collect();
+        let pos: Vec<f64> = pos.into_iter().filter(|&t| inside(t)).collect();
+        let count = neg.len() + usize::from(zero) + pos.len();
+        if count >= MIN_Y_TICKS {
+            // every k-th tick outwards from zero, at most about Y_TICK_TARGET
+            let k = count.div_ceil(Y_TICK_TARGET);
+            let mut out: Vec<f64> = neg.into_iter().step_by(k).collect();
+            out.reverse();
+            if zero {
+                out.push(0.0);
+            }
+            out.extend(pos.into_iter().step_by(k));
+            return out;
+        }
+    }
+
+    // A step of at most 2 × span / Y_TICK_TARGET leaves at least 3 ticks.
+    let step = nice_step(y_max - y_min, Y_TICK_TARGET);
+    ticks(y_min, y_max, step)
+        .into_iter()
+        .filter(|&t| inside(t))
+        .collect()
+}
+
+/// m × 10ⁿ for every `mantissas` m (ascending, in `1..10`) and n with
+/// `lo <= m × 10ⁿ <= hi` (`lo > 0`), smallest first.
+#[allow(clippy::cast_possible_truncation)] // log10 of a finite f64 is within ±308
+fn decade_ticks(lo: f64, hi: f64, mantissas: &[f64]) -> Vec<f64> {
+    if hi < lo {
+        return Vec::new();
+    }
+    let (first, last) = (lo.log10().floor() as i32, hi.log10().floor() as i32);
+    (first..=last)
+        .flat_map(|n| mantissas.iter().map(move |m| m * 10f64.powi(n)))
+        .filter(|t| (lo..=hi).contains(t))
+        .collect()
+}
+
+/// Round step (1, 2 or 5 × 10ⁿ) giving at most about `max_ticks` ticks over
+/// `span`.
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // tick counts are tiny
+pub fn nice_step(span: f64, max_ticks: usize) -> f64 {
+    let raw = span / max_ticks.max(1) as f64;
+    if raw <= 0.0 || !raw.is_finite() {
+        return 1.0;
+    }
+    let mag = 10f64.powf(raw.log10().floor());
+    let norm = raw / mag;
+    let nice = if norm <= 1.0 {
+        1.0
+    } else if norm <= 2.0 {
+        2.0
+    } else if norm <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * mag
+}
+
+/// Every multiple of `step` inside `[lo, hi]`.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)] // tick indices of a finite span; `as` saturates
+pub fn ticks(lo: f64, hi: f64, step: f64) -> Vec<f64> {
+    if step <= 0.0 || !step.is_finite() {
+        return vec![lo];
+    }
+    let first = (lo / step).ceil() as i64;
+    let last = (hi / step).floor() as i64;
+    // Multiply instead of accumulating so ticks stay exactly on multiples.
+    (first..=last).map(|i| i as f64 * step).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ticks in scientific notation, to compare without float noise.
+    fn sci(ticks: &[f64]) -> Vec<String> {
+        ticks.iter().map(|t| format!("{t:.1e}")).collect()
+    }
+
+    #[test]
+    fn y_ticks_are_round_and_between_three_and_eight() {
+        let symlog = Scale::SymLog { threshold: 1.0 };
+        #[rustfmt::skip]
+        let table: [(f64, f64, Scale, &[&str]); 10] = [
+            (0.0, 1.0, Scale::Linear, &["2.0e-1", "4.0e-1", "6.0e-1", "8.0e-1"]),
+            (-3.0, 7.0, Scale::Linear, &["-2.0e0", "0.0e0", "2.0e0", "4.0e0", "6.0e0"]),
+            // a step just over span / 6 rounds up to 1: the fewest ticks
+            (0.99, 4.0, Scale::Linear, &["1.0e0", "2.0e0", "3.0e0"]),
+            // eight powers inside, every other one drawn
+            (1e-3, 1e6, Scale::Log10, &["1.0e-2", "1.0e0", "1.0e2", "1.0e4"]),
+            // too few powers: 1 and 3, then 1, 2 and 5 × 10ⁿ, then linear
+            (1.0, 1000.0, Scale::Log10, &["3.0e0", "1.0e1", "3.0e1", "1.0e2", "3.0e2"]),
+            (1.0, 30.0, Scale::Log10, &["2.0e0", "5.0e0", "1.0e1", "2.0e1"]),
+            (2.0, 9.0, Scale::Log10, &["4.0e0", "6.0e0", "8.0e0"]),
+            (-100.0, 1000.0, symlog, &["-1.0e1", "-1.0e0", "0.0e0", "1.0e0", "1.0e1", "1.0e2"]),
+            (-5.0, 5.0, symlog, &["-1.0e0", "0.0e0", "1.0e0"]),
+            (-0.5, 0.5, symlog, &["-4.0e-1", "-2.0e-1", "0.0e0", "2.0e-1", "4.0e-1"]),
+        ];
+        for (lo, hi, scale, want) in table {
+            let got = y_ticks(lo, hi, scale);
+            assert_eq!(sci(&got), want, "{lo}..{hi} {scale:?}");
+            assert!((MIN_Y_TICKS..=8).contains(&got.len()));
+            assert!(got.iter().all(|&t| t > lo && t < hi));
+        }
+    }
+
+    #[test]
+    fn nice_step_rounds_up_to_1_2_or_5() {
+        for (span, want) in [
+            (1.0, 0.2),
+            (10.0, 2.0),
+            (3.0, 0.5),
+            (3.01, 1.0),
+            (600.0, 100.0),
+        ] {
+            assert!((nice_step(span, 6) - want).abs() < 1e-12, "{span}");
+        }
+        assert!((nice_step(0.0, 6) - 1.0).abs() < f64::EPSILON);
+        assert!((nice_step(f64::INFINITY, 6) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn ticks_include_multiples_on_the_ends() {
+        assert_eq!(ticks(0.0, 1.0, 0.25), [0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(ticks(-3.0, 7.0, 5.0), [0.0, 5.0]);
+        assert_eq!(ticks(2.0, 3.0, 0.0), [2.0]);
+    }
+
+    #[test]
+    fn decade_ticks_stay_within_bounds() {
+        assert_eq!(
+            sci(&decade_ticks(1e-3, 1.0, &[1.0])),
+            ["1.0e-3", "1.0e-2", "1.0e-1", "1.0e0"]
+        );
+        assert_eq!(decade_ticks(2.0, 40.0, &[1.0, 3.0]), [3.0, 10.0, 30.0]);
+        assert!(decade_ticks(5.0, 1.0, &[1.0]).is_empty());
+    }
 }
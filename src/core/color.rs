@@ -1,6 +1,10 @@
 //! Zero-alloc ANSI color wrapper.  No external deps.
 
-use std::{fmt, str};
+use std::{
+    env, fmt,
+    io::{IsTerminal, stdout},
+    str,
+};
 
 #[derive(Debug)]
 pub enum ColorError {
@@ -15,6 +19,49 @@ pub enum AnsiCode {
     Inline { buf: [u8; 20], len: u8 },
 }
 
+/// Default series colors, in assignment order.
+pub const PALETTE: [AnsiCode; 6] = [
+    AnsiCode::industrial_orange(),
+    AnsiCode::cyan(),
+    AnsiCode::magenta(),
+    AnsiCode::green(),
+    AnsiCode::yellow(),
+    AnsiCode::blue(),
+];
+
+/// Sparse to dense, for coloring cells by how many points they hold.
+pub const DENSITY_RAMP: [AnsiCode; 5] = [
+    AnsiCode::blue(),
+    AnsiCode::cyan(),
+    AnsiCode::green(),
+    AnsiCode::yellow(),
+    AnsiCode::red(),
+];
+
+/// When to emit color escapes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ColorMode {
+    Always,
+    /// Color only when stdout is a terminal and `NO_COLOR` is unset or empty.
+    #[default]
+    Auto,
+    Never,
+}
+
+impl ColorMode {
+    /// Resolve against the current environment.
+    #[must_use]
+    pub fn enabled(self) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => {
+                env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) && stdout().is_terminal()
+            }
+        }
+    }
+}
+
 impl AnsiCode {
     #[must_use]
     #[inline]
@@ -92,6 +139,10 @@ impl AnsiCode {
     }
 
     /// Parse color names or `#rrggbb`.  Falls back to hex parser on miss.
+    ///
+    /// # Errors
+    ///
+    /// [`ColorError`] when `s` is neither a known name nor `#rrggbb`.
     pub fn from_name(s: &str) -> Result<Self, ColorError> {
         match s.trim().to_ascii_lowercase().as_str() {
             "black" => Ok(Self::black()),
@@ -107,6 +158,9 @@ impl AnsiCode {
         }
     }
 
+    /// # Errors
+    ///
+    /// [`ColorError`] unless `hex` is six hex digits, optionally after a `#`.
     pub fn from_hex(hex: &str) -> Result<Self, ColorError> {
         let h = hex.trim_start_matches('#');
         if h.len() != 6 {
@@ -116,11 +170,14 @@ impl AnsiCode {
         Ok(Self::rgb(byte(&h[..2])?, byte(&h[2..4])?, byte(&h[4..])?))
     }
 
+    /// The escape sequence (empty if an inline code is somehow not UTF-8).
     #[inline]
+    #[must_use]
     pub fn as_str(&self) -> &str {
         match self {
             Self::Static(s) => s,
-            Self::Inline { buf, len } => str::from_utf8(&buf[..*len as usize]).unwrap(),
+            // built from ASCII only by `rgb`
+            Self::Inline { buf, len } => str::from_utf8(&buf[..*len as usize]).unwrap_or_default(),
         }
     }
 }
@@ -173,6 +230,7 @@ impl fmt::Display for AnsiCode {
 
 /// Wrap `text` in color + reset sequence.
 #[inline]
+#[must_use]
 pub fn colorize(c: &AnsiCode, text: &str) -> String {
     format!("{c}{text}{}", AnsiCode::reset())
 }
@@ -1,6 +1,103 @@
 //! Run-time configuration object + fluent builder.
 
-use crate::core::{color::AnsiCode, error::ConfigError};
+use std::str::FromStr;
+
+use crate::core::{
+    bounds::{Scale, y_label_width},
+    color::{AnsiCode, PALETTE},
+    constants::{BORDER_WIDTH, DECIMAL_PRECISION, FRAME_ROWS, LABEL_GUTTER},
+    error::ConfigError,
+    glyphs::Glyphs,
+    time::TimeFormat,
+};
+
+/// How a braille cell is colored when several series light dots in it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Overlap {
+    /// The series drawn last owns the cell.
+    #[default]
+    LastWins,
+    /// Cells shared by two or more series get a dedicated color.
+    Color(AnsiCode),
+}
+
+/// How each series is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum PlotStyle {
+    /// One dot column per step, lit from its min to its max.
+    #[default]
+    Envelope,
+    /// Points placed along the x axis by time and joined by straight lines.
+    Line,
+    /// One dot column per step, lit from the baseline out to the step.
+    Area(Baseline),
+    /// One dot per `(time, value)` pair, in any order; with `density` each
+    /// cell is colored by how many points fall into it.
+    Scatter { density: bool },
+}
+
+/// Where [`PlotStyle::Area`] fills to.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Baseline {
+    #[default]
+    Zero,
+    /// The bottom of the plot.
+    YMin,
+    Value(f64),
+}
+
+impl Baseline {
+    #[inline]
+    #[must_use]
+    pub fn value(self, cfg: &Config) -> f64 {
+        match self {
+            Self::Zero => 0.0,
+            Self::YMin => cfg.y_min,
+            Self::Value(v) => v,
+        }
+    }
+}
+
+/// Requested size along one axis, overriding the plot size given to
+/// [`Config::builder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extent {
+    /// Characters of the whole frame, borders and labels included.
+    Outer(usize),
+    /// Dots of the plot area.
+    Dots(usize),
+}
+
+impl Extent {
+    /// Plot-area characters, given the dots per character and the characters
+    /// the frame adds around the plot on this axis.
+    #[inline]
+    #[must_use]
+    pub fn plot_chars(self, dots_per_char: usize, frame: usize) -> usize {
+        match self {
+            Self::Outer(n) => n.saturating_sub(frame),
+            Self::Dots(n) => n.div_ceil(dots_per_char),
+        }
+    }
+}
+
+impl FromStr for Extent {
+    type Err = String;
+
+    /// `80` (outer characters) or `160d` / `160dots`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (num, dots) = match s.strip_suffix("dots").or_else(|| s.strip_suffix('d')) {
+            Some(n) => (n, true),
+            None => (s, false),
+        };
+        let n = num
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| format!("invalid size '{s}' (try 80 or 160d)"))?;
+        Ok(if dots { Self::Dots(n) } else { Self::Outer(n) })
+    }
+}
 
 /// Immutable parameters handed to the renderer.
 #[derive(Debug, Clone)]
@@ -9,10 +106,34 @@ pub struct Config {
     pub subtitle: Option<String>,
     pub y_min: f64,
     pub y_max: f64,
+    pub y_scale: Scale,
     pub x_chars: usize,
     pub y_chars: usize,
     pub color: AnsiCode,
     pub x_range: Option<(f64, f64)>,
+    /// Per-series colors; empty means `color` followed by [`PALETTE`].
+    pub palette: Vec<AnsiCode>,
+    pub overlap: Overlap,
+    /// Draw a legend in the bottom bar when two or more layers are named.
+    pub legend: bool,
+    /// How x-axis tick labels are rendered.
+    pub x_format: TimeFormat,
+    /// Faint horizontal rules at the intermediate y ticks.
+    pub gridlines: bool,
+    /// Emit ANSI color escapes; off for plain text.
+    pub colored: bool,
+    /// Never connect two steps more than this many times the median time
+    /// spacing apart.
+    pub gap_threshold: Option<f64>,
+    pub style: PlotStyle,
+    /// Color of area fills below their baseline; `None` keeps the series
+    /// color.
+    pub below_color: Option<AnsiCode>,
+    /// Characters the plot area is drawn with.
+    pub glyphs: Glyphs,
+    /// Pad y-axis labels to at least this width, so that panels stacked
+    /// with the same value line up.
+    pub min_label_width: usize,
 }
 
 impl Config {
@@ -21,6 +142,46 @@ impl Config {
     pub fn builder(x_chars: usize, y_chars: usize) -> ConfigBuilder {
         ConfigBuilder::new(x_chars, y_chars)
     }
+
+    /// Color of the `i`-th series.  Cycles when there are more series than
+    /// colors.
+    #[must_use]
+    pub fn series_color(&self, i: usize) -> AnsiCode {
+        if !self.palette.is_empty() {
+            return self.palette[i % self.palette.len()];
+        }
+        if i == 0 {
+            return self.color;
+        }
+        PALETTE
+            .into_iter()
+            .filter(|c| *c != self.color)
+            .cycle()
+            .nth(i - 1)
+            .unwrap_or(self.color)
+    }
+
+    /// Width of the y-axis labels.
+    #[inline]
+    #[must_use]
+    pub fn label_width(&self) -> usize {
+        y_label_width(self.y_min, self.y_max, self.y_scale, DECIMAL_PRECISION)
+            .max(self.min_label_width)
+    }
+
+    /// Plot width in dots.
+    #[inline]
+    #[must_use]
+    pub const fn dot_columns(&self) -> usize {
+        self.x_chars * self.glyphs.cols()
+    }
+
+    /// Plot height in dots.
+    #[inline]
+    #[must_use]
+    pub const fn dot_rows(&self) -> usize {
+        self.y_chars * self.glyphs.rows()
+    }
 }
 
 /// Fluent builder with zero allocation until `build`.
@@ -32,8 +193,22 @@ pub struct ConfigBuilder {
     subtitle: Option<String>,
     y_min: Option<f64>,
     y_max: Option<f64>,
+    y_scale: Scale,
     x_range: Option<(f64, f64)>,
     color: Option<AnsiCode>,
+    palette: Vec<AnsiCode>,
+    overlap: Overlap,
+    legend: bool,
+    x_format: TimeFormat,
+    gridlines: bool,
+    colored: bool,
+    width: Option<Extent>,
+    height: Option<Extent>,
+    gap_threshold: Option<f64>,
+    style: PlotStyle,
+    below_color: Option<AnsiCode>,
+    glyphs: Glyphs,
+    min_label_width: usize,
 }
 
 impl ConfigBuilder {
@@ -46,8 +221,22 @@ impl ConfigBuilder {
             subtitle: None,
             y_min: None,
             y_max: None,
+            y_scale: Scale::Linear,
             x_range: None,
             color: None,
+            palette: Vec::new(),
+            overlap: Overlap::LastWins,
+            legend: true,
+            x_format: TimeFormat::Number,
+            gridlines: false,
+            colored: true,
+            width: None,
+            height: None,
+            gap_threshold: None,
+            style: PlotStyle::Envelope,
+            below_color: None,
+            glyphs: Glyphs::Braille,
+            min_label_width: 0,
         }
     }
 
@@ -92,6 +281,12 @@ impl ConfigBuilder {
     }
     #[inline]
     #[must_use]
+    pub fn y_scale(mut self, s: Scale) -> Self {
+        self.y_scale = s;
+        self
+    }
+    #[inline]
+    #[must_use]
     pub fn x_range(mut self, lo: f64, hi: f64) -> Self {
         self.x_range = Some((lo, hi));
         self
@@ -102,7 +297,96 @@ impl ConfigBuilder {
         self.color = Some(c);
         self
     }
+    #[inline]
+    #[must_use]
+    pub fn palette(mut self, p: impl Into<Vec<AnsiCode>>) -> Self {
+        self.palette = p.into();
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn overlap(mut self, o: Overlap) -> Self {
+        self.overlap = o;
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn legend(mut self, on: bool) -> Self {
+        self.legend = on;
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn x_format(mut self, f: TimeFormat) -> Self {
+        self.x_format = f;
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn gridlines(mut self, on: bool) -> Self {
+        self.gridlines = on;
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn colored(mut self, on: bool) -> Self {
+        self.colored = on;
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn width(mut self, w: Extent) -> Self {
+        self.width = Some(w);
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn height(mut self, h: Extent) -> Self {
+        self.height = Some(h);
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn gap_threshold(mut self, n: f64) -> Self {
+        self.gap_threshold = Some(n);
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn style(mut self, s: PlotStyle) -> Self {
+        self.style = s;
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn below_color(mut self, c: AnsiCode) -> Self {
+        self.below_color = Some(c);
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn glyphs(mut self, g: Glyphs) -> Self {
+        self.glyphs = g;
+        self
+    }
+    #[inline]
+    #[must_use]
+    pub fn min_label_width(mut self, n: usize) -> Self {
+        self.min_label_width = n;
+        self
+    }
+    /// Fit the whole frame, borders and labels included, into `w`×`h`
+    /// characters.
+    #[inline]
+    #[must_use]
+    pub fn outer_size(self, w: usize, h: usize) -> Self {
+        self.width(Extent::Outer(w)).height(Extent::Outer(h))
+    }
 
+    /// # Errors
+    ///
+    /// [`ConfigError`] when a y bound is missing, the y range is empty or
+    /// reversed, or the scale cannot show it.
     pub fn build(self) -> Result<Config, ConfigError> {
         let y_min = self.y_min.ok_or(ConfigError::MissingField("y_min"))?;
         let y_max = self.y_max.ok_or(ConfigError::MissingField("y_max"))?;
@@ -112,15 +396,42 @@ impl ConfigBuilder {
                 hi: y_max,
             });
         }
+        match self.y_scale {
+            Scale::Log10 if y_min <= 0.0 => return Err(ConfigError::NonPositiveLog { lo: y_min }),
+            Scale::SymLog { threshold } if threshold.is_nan() || threshold <= 0.0 => {
+                return Err(ConfigError::InvalidThreshold(threshold));
+            }
+            _ => {}
+        }
+        let label_w =
+            y_label_width(y_min, y_max, self.y_scale, DECIMAL_PRECISION).max(self.min_label_width);
+        let x_chars = self.width.map_or(self.x_chars, |w| {
+            w.plot_chars(self.glyphs.cols(), BORDER_WIDTH + LABEL_GUTTER + label_w)
+        });
+        let y_chars = self.height.map_or(self.y_chars, |h| {
+            h.plot_chars(self.glyphs.rows(), FRAME_ROWS)
+        });
         Ok(Config {
             title: self.title.unwrap_or_default(),
             subtitle: self.subtitle,
             y_min,
             y_max,
-            x_chars: self.x_chars,
-            y_chars: self.y_chars,
+            y_scale: self.y_scale,
+            x_chars,
+            y_chars,
             color: self.color.unwrap_or_else(AnsiCode::industrial_orange),
             x_range: self.x_range,
+            palette: self.palette,
+            overlap: self.overlap,
+            legend: self.legend,
+            x_format: self.x_format,
+            gridlines: self.gridlines,
+            colored: self.colored,
+            gap_threshold: self.gap_threshold,
+            style: self.style,
+            below_color: self.below_color,
+            glyphs: self.glyphs,
+            min_label_width: self.min_label_width,
         })
     }
 }
@@ -131,3 +442,28 @@ impl From<ConfigBuilder> for Result<Config, ConfigError> {
         b.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extents_parse_characters_or_dots() {
+        assert_eq!("80".parse(), Ok(Extent::Outer(80)));
+        assert_eq!(" 40 ".parse(), Ok(Extent::Outer(40)));
+        assert_eq!("160d".parse(), Ok(Extent::Dots(160)));
+        assert_eq!("160dots".parse(), Ok(Extent::Dots(160)));
+        assert_eq!("160 d".parse(), Ok(Extent::Dots(160)));
+        for bad in ["", "d", "80px", "-5", "1.5", "dots80"] {
+            let err = bad.parse::<Extent>().unwrap_err();
+            assert!(err.starts_with("invalid size"), "{bad:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn extents_size_the_plot_inside_the_frame() {
+        assert_eq!(Extent::Outer(80).plot_chars(2, 12), 68);
+        assert_eq!(Extent::Outer(10).plot_chars(2, 12), 0);
+        assert_eq!(Extent::Dots(161).plot_chars(2, 12), 81);
+    }
+}
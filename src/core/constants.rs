@@ -5,16 +5,26 @@ pub const BORDER_WIDTH: usize = 2;
 /// One character of space between x axis labels and the plotted data
 pub const LABEL_GUTTER: usize = 1;
 
+/// Rows around the plot: title bar, top padding, x-axis labels, bottom bar
+pub const FRAME_ROWS: usize = 4;
+
 /// Graph must be at least 7 characters tall
 pub const MIN_GRAPH_HEIGHT: usize = 7;
 /// Graph must be at least 14 characters wide
 pub const MIN_GRAPH_WIDTH: usize = 14;
+/// A panel of a composed frame may be as short as 4 characters
+pub const MIN_PANEL_HEIGHT: usize = 4;
 
 /// Braille has 2 horizontal dots and four vertical dots that can be either off or on
 pub const BRAILLE_HORIZONTAL_RESOLUTION: usize = 2;
 /// Braille has 2 horizontal dots and four vertical dots that can be either off or on
 pub const BRAILLE_VERTICAL_RESOLUTION: usize = 4;
 
+/// Aim for about this many round-valued y-axis ticks (1, 2 or 5 × 10ⁿ apart).
+pub const Y_TICK_TARGET: usize = 6;
+/// Draw at least this many y-axis ticks between the extremes.
+pub const MIN_Y_TICKS: usize = 3;
+
 /// Numbers are rounded to the first decimal place.
 ///
 /// 14.837 becomes 14.84
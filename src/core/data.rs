@@ -1,11 +1,19 @@
 //! Memory-efficient CSV loader with zero-allocation float parsing.
+//!
+//! The time column may hold plain numbers or ISO-8601 timestamps, which are
+//! converted to Unix epoch seconds.
 
 use std::{
     error::Error,
     fmt::{self, Display},
     io::{BufRead, BufReader, Read},
+    ops::Range,
+    str::FromStr,
+    sync::mpsc::{self, Receiver},
 };
 
+use crate::core::time::{TimeFormat, parse_timestamp};
+
 // --- Public Row Structs ---
 #[derive(Clone, Copy)]
 pub struct DataTimeStep {
@@ -14,6 +22,19 @@ pub struct DataTimeStep {
     pub max: f64,
 }
 
+/// One named column (or min/max column pair) pulled out of a [`CsvTable`].
+#[derive(Clone)]
+pub struct Series {
+    pub name: String,
+    pub steps: Vec<DataTimeStep>,
+    /// `breaks[i]`: step `i` is not joined to the one before, because the
+    /// samples behind them are further apart than
+    /// [`Config::gap_threshold`](crate::core::config::Config::gap_threshold)
+    /// allows.  Set from [`Binner::breaks`](crate::render::Binner::breaks)
+    /// for binned steps; empty to detect gaps from `steps` themselves.
+    pub breaks: Vec<bool>,
+}
+
 // --- Error Handling ---
 #[derive(Debug)]
 pub struct ParseCsvError {
@@ -26,6 +47,8 @@ pub enum ParseErrorKind {
     Io(std::io::Error),
     BadColumnCount(usize),
     BadFloat { field: &'static str, text: String },
+    RaggedRow { expected: usize, got: usize },
+    UnknownColumn(String),
 }
 
 impl Display for ParseCsvError {
@@ -38,6 +61,14 @@ impl Display for ParseCsvError {
             ParseErrorKind::BadFloat { field, text } => {
                 write!(f, "line {}: invalid {} value '{}'", self.line, field, text)
             }
+            ParseErrorKind::RaggedRow { expected, got } => {
+                write!(
+                    f,
+                    "line {}: expected {} columns, got {}",
+                    self.line, expected, got
+                )
+            }
+            ParseErrorKind::UnknownColumn(c) => write!(f, "no column named or numbered '{c}'"),
         }
     }
 }
@@ -96,44 +127,110 @@ fn parse_f64(bytes: &[u8], line: usize, field: &'static str) -> Result<f64, Pars
     }
 }
 
-// --- Fast CSV ingest ---
-const BUF_CAP: usize = 1 << 20; // 1 MiB
-
-pub fn read_csv_fast<R: Read>(src: R) -> Result<Vec<DataTimeStep>, ParseCsvError> {
-    let mut rdr = BufReader::with_capacity(BUF_CAP, src);
-    let mut buf = Vec::<u8>::with_capacity(256);
-    let mut data = Vec::<DataTimeStep>::new();
-    let mut saw_first = false;
-    let mut line_no = 0usize;
-
+/// Read the next non-blank, non-comment line into `buf` (newline stripped,
+/// unicode minus normalised).  Returns `false` on EOF.
+fn next_record<R: BufRead>(
+    rdr: &mut R,
+    buf: &mut Vec<u8>,
+    line_no: &mut usize,
+) -> Result<bool, ParseCsvError> {
     loop {
         buf.clear();
-        let n = rdr.read_until(b'\n', &mut buf).map_err(|e| ParseCsvError {
-            line: line_no,
+        let n = rdr.read_until(b'\n', buf).map_err(|e| ParseCsvError {
+            line: *line_no,
             kind: ParseErrorKind::Io(e),
         })?;
         if n == 0 {
-            break;
+            return Ok(false);
         }
-        line_no += 1;
+        *line_no += 1;
 
-        if buf.ends_with(b"\n") {
-            buf.pop();
-        }
-        if buf.ends_with(b"\r") {
-            buf.pop();
+        if clean_line(buf) {
+            return Ok(true);
         }
+    }
+}
+
+/// Strip the line ending and normalise unicode minus in place.  Returns
+/// `false` for blank and `#` comment lines.
+#[inline]
+fn clean_line(buf: &mut Vec<u8>) -> bool {
+    if buf.ends_with(b"\n") {
+        buf.pop();
+    }
+    if buf.ends_with(b"\r") {
+        buf.pop();
+    }
+    normalize_unicode_minus(buf);
+    !buf.is_empty() && buf[0] != b'#'
+}
+
+/// A line is a header if its first field is neither numeric nor a timestamp.
+#[inline]
+fn is_header(line: &[u8]) -> bool {
+    let first = trim(&line[..line.iter().position(|&b| b == b',').unwrap_or(line.len())]);
+    lexical_core::parse::<f64>(first).is_err() && parse_timestamp(first).is_none()
+}
+
+/// Time field: a float, or a timestamp converted to epoch seconds.
+#[inline]
+fn parse_time(bytes: &[u8], line: usize) -> Result<(f64, TimeFormat), ParseCsvError> {
+    if lexical_core::parse::<f64>(bytes).is_ok() {
+        return parse_f64(bytes, line, "time").map(|v| (v, TimeFormat::Number));
+    }
+    parse_timestamp(bytes).ok_or_else(|| ParseCsvError {
+        line,
+        kind: ParseErrorKind::BadFloat {
+            field: "time",
+            text: String::from_utf8_lossy(bytes).into_owned(),
+        },
+    })
+}
 
-        normalize_unicode_minus(&mut buf);
-        if buf.is_empty() || buf[0] == b'#' {
-            continue;
+/// Split `line` on commas into trimmed field ranges (reuses `out`).
+#[inline]
+fn split_fields(line: &[u8], out: &mut Vec<Range<usize>>) {
+    out.clear();
+    let mut start = 0;
+    loop {
+        let end = line[start..]
+            .iter()
+            .position(|&b| b == b',')
+            .map_or(line.len(), |p| start + p);
+        let (mut lo, mut hi) = (start, end);
+        while lo < hi && line[lo].is_ascii_whitespace() {
+            lo += 1;
         }
+        while hi > lo && line[hi - 1].is_ascii_whitespace() {
+            hi -= 1;
+        }
+        out.push(lo..hi);
+        if end == line.len() {
+            break;
+        }
+        start = end + 1;
+    }
+}
+
+// --- Fast CSV ingest ---
+const BUF_CAP: usize = 1 << 20; // 1 MiB
 
+/// # Errors
+///
+/// [`ParseCsvError`] on the first line that is not `time,min[,max]`, or
+/// when there are no data rows.
+pub fn read_csv_fast<R: Read>(src: R) -> Result<Vec<DataTimeStep>, ParseCsvError> {
+    let mut rdr = BufReader::with_capacity(BUF_CAP, src);
+    let mut buf = Vec::<u8>::with_capacity(256);
+    let mut data = Vec::<DataTimeStep>::new();
+    let mut saw_first = false;
+    let mut line_no = 0usize;
+
+    while next_record(&mut rdr, &mut buf, &mut line_no)? {
         // simple header detection (non-numeric first field)
         if !saw_first {
             saw_first = true;
-            let first = buf.iter().position(|&b| b == b',').unwrap_or(buf.len());
-            if lexical_core::parse::<f64>(trim(&buf[..first])).is_err() {
+            if is_header(&buf) {
                 continue;
             }
         }
@@ -161,16 +258,16 @@ pub fn read_csv_fast<R: Read>(src: R) -> Result<Vec<DataTimeStep>, ParseCsvError
             }
             start = end + 1;
         }
-        if idx < 2 {
+        let [Some(time), Some(min), max] = cols else {
             return Err(ParseCsvError {
                 line: line_no,
                 kind: ParseErrorKind::BadColumnCount(idx),
             });
-        }
+        };
 
-        let t = parse_f64(cols[0].unwrap(), line_no, "time")?;
-        let min = parse_f64(cols[1].unwrap(), line_no, "min")?;
-        let max = match cols[2] {
+        let (t, _) = parse_time(time, line_no)?;
+        let min = parse_f64(min, line_no, "min")?;
+        let max = match max {
             Some(c) if !c.is_empty() => parse_f64(c, line_no, "max")?,
             _ => min,
         };
@@ -185,6 +282,9 @@ pub fn read_csv_fast<R: Read>(src: R) -> Result<Vec<DataTimeStep>, ParseCsvError
     Ok(data)
 }
 
+/// # Errors
+///
+/// As [`read_csv_fast`], and when the file cannot be opened.
 pub fn read_csv_from_path(path: &str) -> Result<Vec<DataTimeStep>, ParseCsvError> {
     if path == "-" {
         read_csv_fast(std::io::stdin())
@@ -196,3 +296,539 @@ pub fn read_csv_from_path(path: &str) -> Result<Vec<DataTimeStep>, ParseCsvError
         })?)
     }
 }
+
+// --- Multi-series ingest ---
+
+/// Column-oriented CSV: one shared time column followed by any number of
+/// value columns.
+///
+/// Empty cells are stored as `NaN`; parsed values are always finite, so the
+/// two can never be confused.
+#[derive(Default)]
+pub struct CsvTable {
+    /// One name per column, time column included.  Synthesised as `colN`
+    /// (1-based) when the file has no header row.
+    pub headers: Vec<String>,
+    pub time: Vec<f64>,
+    /// What the time column held; epoch seconds for the calendar kinds.
+    pub time_kind: TimeFormat,
+    /// `columns[c][row]` for value column `c` (header index `c + 1`).
+    pub columns: Vec<Vec<f64>>,
+    kind_fixed: bool,
+    ranges: Vec<Range<usize>>, // field scratch, reused per record
+}
+
+/// A column picked by header name or by 1-based field number (as in
+/// `cut -f`, so field 1 is the time column).
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnRef {
+    Index(usize),
+    Name(String),
+}
+
+/// A single column, or a `min:max` pair forming one envelope series.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnSpec {
+    pub min: ColumnRef,
+    pub max: Option<ColumnRef>,
+}
+
+impl FromStr for ColumnRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("empty column reference".into());
+        }
+        Ok(s.parse::<usize>()
+            .map_or_else(|_| Self::Name(s.to_owned()), Self::Index))
+    }
+}
+
+impl FromStr for ColumnSpec {
+    type Err = String;
+
+    /// `cpu`, `3` or `lo:hi`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some((lo, hi)) => Ok(Self {
+                min: lo.parse()?,
+                max: Some(hi.parse()?),
+            }),
+            None => Ok(Self {
+                min: s.parse()?,
+                max: None,
+            }),
+        }
+    }
+}
+
+impl CsvTable {
+    /// An empty table; the first record pushed fixes the column layout.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed one raw line (line ending optional).  Blank and `#` comment lines
+    /// are ignored; the first record may be a header.
+    ///
+    /// # Errors
+    ///
+    /// [`ParseCsvError`] when the row has the wrong number of fields or a
+    /// cell is not a number (or timestamp, in the time column).
+    pub fn push_line(&mut self, line: &mut Vec<u8>, line_no: usize) -> Result<(), ParseCsvError> {
+        if clean_line(line) {
+            self.push_record(line, line_no)?;
+        }
+        Ok(())
+    }
+
+    /// Drop every data row but keep headers and time kind, so a streaming
+    /// reader can hand rows off and keep parsing.
+    pub fn clear_rows(&mut self) {
+        self.time.clear();
+        for c in &mut self.columns {
+            c.clear();
+        }
+    }
+
+    fn push_record(&mut self, line: &[u8], line_no: usize) -> Result<(), ParseCsvError> {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        split_fields(line, &mut ranges);
+        let result = self.parse_record(line, &ranges, line_no);
+        self.ranges = ranges;
+        result
+    }
+
+    fn parse_record(
+        &mut self,
+        line: &[u8],
+        ranges: &[Range<usize>],
+        line_no: usize,
+    ) -> Result<(), ParseCsvError> {
+        let field = |i: usize| &line[ranges[i].clone()];
+        if self.headers.is_empty() {
+            if ranges.len() < 2 {
+                return Err(ParseCsvError {
+                    line: line_no,
+                    kind: ParseErrorKind::BadColumnCount(ranges.len()),
+                });
+            }
+            self.columns = vec![Vec::new(); ranges.len() - 1];
+            if is_header(line) {
+                self.headers = (0..ranges.len())
+                    .map(field)
+                    .map(|f| {
+                        let f = f.strip_prefix(b"\"").unwrap_or(f);
+                        let f = f.strip_suffix(b"\"").unwrap_or(f);
+                        String::from_utf8_lossy(f).into_owned()
+                    })
+                    .collect();
+                return Ok(());
+            }
+            self.headers = (1..=ranges.len()).map(|i| format!("col{i}")).collect();
+        }
+
+        if ranges.len() != self.headers.len() {
+            return Err(ParseCsvError {
+                line: line_no,
+                kind: ParseErrorKind::RaggedRow {
+                    expected: self.headers.len(),
+                    got: ranges.len(),
+                },
+            });
+        }
+
+        let (t, kind) = parse_time(field(0), line_no)?;
+        let time_kind = match (self.kind_fixed, self.time_kind, kind) {
+            (false, _, k) => k,
+            (true, a, b) if a == b => a,
+            (
+                true,
+                TimeFormat::Date | TimeFormat::DateTime,
+                TimeFormat::Date | TimeFormat::DateTime,
+            ) => TimeFormat::DateTime,
+            // numbers and timestamps can't share an axis
+            (true, _, _) => {
+                return Err(ParseCsvError {
+                    line: line_no,
+                    kind: ParseErrorKind::BadFloat {
+                        field: "time",
+                        text: String::from_utf8_lossy(field(0)).into_owned(),
+                    },
+                });
+            }
+        };
+
+        // Parse every cell before pushing so a bad row leaves no trace.
+        let row_start = self.time.len();
+        for (c, col) in self.columns.iter_mut().enumerate() {
+            let f = field(c + 1);
+            let v = if f.is_empty() {
+                f64::NAN
+            } else {
+                match parse_f64(f, line_no, "value") {
+                    Ok(v) => v,
+                    Err(e) => {
+                        for col in &mut self.columns[..c] {
+                            col.truncate(row_start);
+                        }
+                        return Err(e);
+                    }
+                }
+            };
+            col.push(v);
+        }
+        self.time.push(t);
+        self.time_kind = time_kind;
+        self.kind_fixed = true;
+        Ok(())
+    }
+
+    /// Resolve a column reference to an index into `columns`.
+    fn resolve(&self, c: &ColumnRef) -> Result<usize, ParseCsvError> {
+        let found = match c {
+            ColumnRef::Index(i) if *i >= 2 && *i <= self.headers.len() => Some(i - 2),
+            ColumnRef::Index(_) => None,
+            ColumnRef::Name(n) => self.headers.iter().skip(1).position(|h| h == n),
+        };
+        found.ok_or_else(|| ParseCsvError {
+            line: 0,
+            kind: ParseErrorKind::UnknownColumn(match c {
+                ColumnRef::Index(i) => i.to_string(),
+                ColumnRef::Name(n) => n.clone(),
+            }),
+        })
+    }
+
+    /// Display name of a series: the header, or `min/max` for a pair.
+    ///
+    /// # Errors
+    ///
+    /// [`ParseCsvError`] when `spec` names a column the table does not have.
+    pub fn series_name(&self, spec: &ColumnSpec) -> Result<String, ParseCsvError> {
+        let lo = self.resolve(&spec.min)?;
+        Ok(
+            match spec.max.as_ref().map(|m| self.resolve(m)).transpose()? {
+                Some(h) => format!("{}/{}", self.headers[lo + 1], self.headers[h + 1]),
+                None => self.headers[lo + 1].clone(),
+            },
+        )
+    }
+
+    /// Append the selected column(s) of every row to `out`, in file order.
+    /// Rows whose (min) cell is empty are skipped; an empty max cell falls
+    /// back to the min value, as in [`read_csv_fast`].
+    ///
+    /// # Errors
+    ///
+    /// As [`CsvTable::series_name`].
+    pub fn append_series(
+        &self,
+        spec: &ColumnSpec,
+        out: &mut Vec<DataTimeStep>,
+    ) -> Result<(), ParseCsvError> {
+        let lo = self.resolve(&spec.min)?;
+        let hi = spec.max.as_ref().map(|m| self.resolve(m)).transpose()?;
+        self.push_steps(lo, hi, out);
+        Ok(())
+    }
+
+    /// [`CsvTable::append_series`] for resolved column indices.
+    fn push_steps(&self, lo: usize, hi: Option<usize>, out: &mut Vec<DataTimeStep>) {
+        out.reserve(self.time.len());
+        for (row, &time) in self.time.iter().enumerate() {
+            let min = self.columns[lo][row];
+            if min.is_nan() {
+                continue;
+            }
+            let max = hi.map_or(min, |h| self.columns[h][row]);
+            let max = if max.is_nan() { min } else { max };
+            out.push(DataTimeStep { time, min, max });
+        }
+    }
+
+    /// Extract one series, sorted by time.
+    ///
+    /// # Errors
+    ///
+    /// As [`CsvTable::series_name`].
+    pub fn series(&self, spec: &ColumnSpec) -> Result<Series, ParseCsvError> {
+        let lo = self.resolve(&spec.min)?;
+        let hi = spec.max.as_ref().map(|m| self.resolve(m)).transpose()?;
+        Ok(self.series_at(lo, hi, self.series_name(spec)?))
+    }
+
+    /// [`CsvTable::series`] for resolved column indices.
+    fn series_at(&self, lo: usize, hi: Option<usize>, name: String) -> Series {
+        let mut steps = Vec::new();
+        self.push_steps(lo, hi, &mut steps);
+        if !steps.windows(2).all(|w| w[0].time <= w[1].time) {
+            steps.sort_by(|l, r| l.time.total_cmp(&r.time));
+        }
+        Series {
+            name,
+            steps,
+            breaks: Vec::new(),
+        }
+    }
+
+    /// Extract several series in the order given.
+    ///
+    /// # Errors
+    ///
+    /// As [`CsvTable::series_name`], for the first bad spec.
+    pub fn select(&self, specs: &[ColumnSpec]) -> Result<Vec<Series>, ParseCsvError> {
+        specs.iter().map(|s| self.series(s)).collect()
+    }
+
+    /// One spec per value column.
+    #[must_use]
+    pub fn column_specs(&self) -> Vec<ColumnSpec> {
+        (0..self.columns.len())
+            .map(|c| ColumnSpec {
+                min: ColumnRef::Index(c + 2),
+                max: None,
+            })
+            .collect()
+    }
+
+    /// One series per value column.
+    #[must_use]
+    pub fn all_series(&self) -> Vec<Series> {
+        (0..self.columns.len())
+            .map(|c| self.series_at(c, None, self.headers[c + 1].clone()))
+            .collect()
+    }
+}
+
+/// Read a CSV with a time column and any number of value columns.
+///
+/// The header row (if any) is kept for [`CsvTable::series`] lookups.  Every
+/// row must have the same number of fields as the first one.
+///
+/// # Errors
+///
+/// [`ParseCsvError`] on the first malformed row, or when there are no data
+/// rows.
+pub fn read_csv_table<R: Read>(src: R) -> Result<CsvTable, ParseCsvError> {
+    let mut rdr = BufReader::with_capacity(BUF_CAP, src);
+    let mut buf = Vec::<u8>::with_capacity(256);
+    let mut line_no = 0usize;
+    let mut table = CsvTable::new();
+
+    while next_record(&mut rdr, &mut buf, &mut line_no)? {
+        table.push_record(&buf, line_no)?;
+    }
+    if table.time.is_empty() {
+        return Err(ParseCsvError {
+            line: 0,
+            kind: ParseErrorKind::BadColumnCount(0),
+        });
+    }
+    Ok(table)
+}
+
+/// # Errors
+///
+/// As [`read_csv_table`], and when the file cannot be opened.
+pub fn read_csv_table_from_path(path: &str) -> Result<CsvTable, ParseCsvError> {
+    if path == "-" {
+        read_csv_table(std::io::stdin())
+    } else {
+        use std::fs::File;
+        read_csv_table(File::open(path).map_err(|e| ParseCsvError {
+            line: 0,
+            kind: ParseErrorKind::Io(e),
+        })?)
+    }
+}
+
+// --- Streaming ---
+
+/// Tail `path` (or stdin for `-`) on a background thread, sending each
+/// complete line as it appears.
+///
+/// A file is polled every `poll` once its end is reached, like `tail -f`, so
+/// the channel stays open until the receiver is dropped.  Each idle poll
+/// sends an empty line, which readers skip; the thread exits as soon as that
+/// send fails.  Stdin disconnects the channel at EOF.
+///
+/// # Errors
+///
+/// [`ParseCsvError`] when `path` cannot be opened; read errors later on
+/// arrive through the channel.
+pub fn follow_lines(
+    path: &str,
+    poll: std::time::Duration,
+) -> Result<Receiver<Result<Vec<u8>, ParseCsvError>>, ParseCsvError> {
+    if path == "-" {
+        return Ok(follow_reader(std::io::stdin(), false, poll));
+    }
+    let file = std::fs::File::open(path).map_err(|e| ParseCsvError {
+        line: 0,
+        kind: ParseErrorKind::Io(e),
+    })?;
+    Ok(follow_reader(file, true, poll))
+}
+
+/// [`follow_lines`] on any reader: with `tail` its end is polled like a
+/// file's, otherwise EOF disconnects the channel like stdin's.
+pub fn follow_reader<R: Read + Send + 'static>(
+    src: R,
+    tail: bool,
+    poll: std::time::Duration,
+) -> Receiver<Result<Vec<u8>, ParseCsvError>> {
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        let mut rdr = BufReader::with_capacity(BUF_CAP, src);
+        let mut buf = Vec::<u8>::with_capacity(256);
+        loop {
+            match rdr.read_until(b'\n', &mut buf) {
+                // Partial line at EOF: the writer is mid-line, keep it.
+                Ok(_) if !buf.ends_with(b"\n") => {
+                    if !tail {
+                        if !buf.is_empty() {
+                            let _ = tx.send(Ok(std::mem::take(&mut buf)));
+                        }
+                        return;
+                    }
+                    // Nothing new: probe the receiver so the thread ends
+                    // once it is dropped.
+                    if tx.send(Ok(Vec::new())).is_err() {
+                        return;
+                    }
+                    std::thread::sleep(poll);
+                }
+                Ok(_) => {
+                    if tx.send(Ok(std::mem::take(&mut buf))).is_err() {
+                        return;
+                    }
+                }
+                Err(e) => {
+                    let _ = tx.send(Err(ParseCsvError {
+                        line: 0,
+                        kind: ParseErrorKind::Io(e),
+                    }));
+                    return;
+                }
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "time,cpu,mem\n0,1,10\n1,2,20\n2,3,30\n";
+
+    fn spec(s: &str) -> ColumnSpec {
+        s.parse().unwrap()
+    }
+
+    fn mins(s: &Series) -> Vec<f64> {
+        s.steps.iter().map(|p| p.min).collect()
+    }
+
+    #[test]
+    fn column_specs_parse_names_numbers_and_pairs() {
+        let name = |n: &str| ColumnRef::Name(n.to_owned());
+        assert_eq!(spec("cpu").min, name("cpu"));
+        assert_eq!(spec(" mem ").min, name("mem"));
+        assert_eq!(spec("3").min, ColumnRef::Index(3));
+        assert_eq!(
+            spec("cpu:3"),
+            ColumnSpec {
+                min: name("cpu"),
+                max: Some(ColumnRef::Index(3)),
+            }
+        );
+        for bad in ["", " ", "cpu:", ":mem"] {
+            assert!(bad.parse::<ColumnSpec>().is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn series_are_selected_by_name_or_field_number() {
+        let table = read_csv_table(CSV.as_bytes()).unwrap();
+        let by_name = table.series(&spec("mem")).unwrap();
+        let by_index = table.series(&spec("3")).unwrap();
+        assert_eq!(by_name.name, "mem");
+        assert_eq!(by_index.name, "mem");
+        assert_eq!(mins(&by_name), [10.0, 20.0, 30.0]);
+        assert_eq!(mins(&by_index), mins(&by_name));
+
+        let pair = table.series(&spec("cpu:mem")).unwrap();
+        assert_eq!(pair.name, "cpu/mem");
+        assert_eq!((pair.steps[1].min, pair.steps[1].max), (2.0, 20.0));
+
+        let all = table.all_series();
+        assert_eq!(
+            all.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+            ["cpu", "mem"]
+        );
+    }
+
+    #[test]
+    fn unknown_columns_are_reported_by_name() {
+        let table = read_csv_table(CSV.as_bytes()).unwrap();
+        // field 1 is the time column, 4 does not exist
+        for (bad, shown) in [
+            ("disk", "disk"),
+            ("1", "1"),
+            ("4", "4"),
+            ("cpu:disk", "disk"),
+        ] {
+            let err = table.series(&spec(bad)).err().unwrap();
+            assert!(matches!(&err.kind, ParseErrorKind::UnknownColumn(c) if c == shown));
+            assert_eq!(
+                err.to_string(),
+                format!("no column named or numbered '{shown}'")
+            );
+        }
+    }
+
+    #[test]
+    fn header_only_input_has_columns_but_no_rows() {
+        let mut table = CsvTable::new();
+        table
+            .push_line(&mut b"time,cpu,mem\r\n".to_vec(), 1)
+            .unwrap();
+        assert_eq!(table.headers, ["time", "cpu", "mem"]);
+        assert!(table.time.is_empty());
+        let all = table.all_series();
+        assert_eq!(all.len(), 2);
+        assert!(all.iter().all(|s| s.steps.is_empty()));
+        assert!(table.series(&spec("mem")).unwrap().steps.is_empty());
+
+        // rows arriving later fill the same columns
+        table.push_line(&mut b"5,1,2".to_vec(), 2).unwrap();
+        assert_eq!(mins(&table.series(&spec("mem")).unwrap()), [2.0]);
+
+        // a whole file without rows is an error
+        assert!(read_csv_table("time,cpu\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn a_malformed_first_row_does_not_fix_the_time_kind() {
+        let mut table = CsvTable::new();
+        table.push_line(&mut b"time,cpu".to_vec(), 1).unwrap();
+        // a number in the time column, but a bad cell
+        assert!(table.push_line(&mut b"5,oops".to_vec(), 2).is_err());
+        assert!(table.time.is_empty() && table.columns[0].is_empty());
+
+        table.push_line(&mut b"2024-03-01,1".to_vec(), 3).unwrap();
+        table.push_line(&mut b"2024-03-02,2".to_vec(), 4).unwrap();
+        assert_eq!(table.time_kind, TimeFormat::Date);
+        assert_eq!(table.time.len(), 2);
+
+        // once a row is in, the kind is fixed
+        assert!(table.push_line(&mut b"7,3".to_vec(), 5).is_err());
+        assert_eq!(table.time_kind, TimeFormat::Date);
+    }
+}
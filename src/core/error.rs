@@ -8,7 +8,20 @@ use crate::core::{color::ColorError, data::ParseCsvError};
 #[derive(Debug)]
 pub enum ConfigError {
     MissingField(&'static str),
-    InvalidRange { lo: f64, hi: f64 },
+    InvalidRange {
+        lo: f64,
+        hi: f64,
+    },
+    /// A log axis can't show zero or negative values.
+    NonPositiveLog {
+        lo: f64,
+    },
+    InvalidThreshold(f64),
+    /// A sparkline needs at least one character cell.
+    ZeroSize {
+        width: usize,
+        height: usize,
+    },
 }
 
 impl fmt::Display for ConfigError {
@@ -16,6 +29,16 @@ impl fmt::Display for ConfigError {
         match self {
             ConfigError::MissingField(x) => write!(f, "configuration missing field `{x}`"),
             ConfigError::InvalidRange { lo, hi } => write!(f, "y_min {lo} must be < y_max {hi}"),
+            ConfigError::NonPositiveLog { lo } => write!(
+                f,
+                "log scale needs values > 0, but y_min is {lo} (try a symlog scale or a positive y_min)"
+            ),
+            ConfigError::InvalidThreshold(t) => {
+                write!(f, "symlog threshold {t} must be > 0")
+            }
+            ConfigError::ZeroSize { width, height } => {
+                write!(f, "sparkline size {width}×{height} must be at least 1×1")
+            }
         }
     }
 }
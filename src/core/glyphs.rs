@@ -0,0 +1,169 @@
+//! Cell encoders: how the dots inside one character cell become a glyph.
+//!
+//! Every encoder packs a `cols × rows` grid of dots into a cell mask, dot
+//! `(x, y)` being bit `y * cols + x`, and maps the mask to one character.
+
+/// Unicode braille: dot `(x, y)` to its bit in the U+2800 block.
+const BRAILLE_DOT_POSITIONS: [[u8; 4]; 2] = [
+    [0, 1, 2, 6], // left  column: dots 1,2,3,7
+    [3, 4, 5, 7], // right column: dots 4,5,6,8
+];
+const BRAILLE_UNICODE_BASE: u32 = 0x2800;
+
+/// Quadrant blocks by mask (top-left, top-right, bottom-left, bottom-right).
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+/// Sextants live at U+1FB00 in mask order, minus the four patterns that
+/// already exist elsewhere (blank, full, left and right half).
+const SEXTANT_BASE: u32 = 0x1FB00;
+const SEXTANT_LEFT: u8 = 0b01_0101;
+const SEXTANT_RIGHT: u8 = 0b10_1010;
+
+/// Character set for the plot area.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Glyphs {
+    /// 2×4 braille dots (U+2800).
+    #[default]
+    Braille,
+    /// 1×2 half blocks (`▀ ▄ █`).
+    HalfBlock,
+    /// 2×2 quadrant blocks (`▖ ▞ ▙ …`).
+    Quadrant,
+    /// 2×3 sextants (U+1FB00, Unicode 13).
+    Sextant,
+    /// 1×2 in plain ASCII (`' . :`), with an ASCII frame.
+    Ascii,
+}
+
+impl Glyphs {
+    /// Dots per cell across.
+    #[inline]
+    #[must_use]
+    pub const fn cols(self) -> usize {
+        match self {
+            Self::HalfBlock | Self::Ascii => 1,
+            Self::Braille | Self::Quadrant | Self::Sextant => 2,
+        }
+    }
+
+    /// Dots per cell down.
+    #[inline]
+    #[must_use]
+    pub const fn rows(self) -> usize {
+        match self {
+            Self::HalfBlock | Self::Quadrant | Self::Ascii => 2,
+            Self::Sextant => 3,
+            Self::Braille => 4,
+        }
+    }
+
+    /// The glyph for a cell mask (bits past `cols × rows` are ignored).
+    #[must_use]
+    pub fn encode(self, mask: u8) -> char {
+        let full = u8::MAX >> (8 - self.cols() * self.rows());
+        let mask = mask & full;
+        match self {
+            Self::Braille => {
+                let mut dots = 0u8;
+                for (i, xs) in BRAILLE_DOT_POSITIONS.iter().enumerate() {
+                    for (y, &dot) in xs.iter().enumerate() {
+                        if mask & (1 << (y * 2 + i)) != 0 {
+                            dots |= 1 << dot;
+                        }
+                    }
+                }
+                char::from_u32(BRAILLE_UNICODE_BASE + u32::from(dots)).unwrap_or(' ')
+            }
+            Self::HalfBlock => [' ', '▀', '▄', '█'][usize::from(mask)],
+            Self::Quadrant => QUADRANTS[usize::from(mask)],
+            Self::Sextant => match mask {
+                0 => ' ',
+                SEXTANT_LEFT => '▌',
+                SEXTANT_RIGHT => '▐',
+                m if m == full => '█',
+                m => {
+                    let skipped = u32::from(m > SEXTANT_LEFT) + u32::from(m > SEXTANT_RIGHT);
+                    char::from_u32(SEXTANT_BASE + u32::from(m) - 1 - skipped).unwrap_or(' ')
+                }
+            },
+            Self::Ascii => [' ', '\'', '.', ':'][usize::from(mask)],
+        }
+    }
+
+    /// Legend color sample: a full cell (`#` in ASCII).
+    #[inline]
+    #[must_use]
+    pub fn swatch(self) -> char {
+        match self {
+            Self::Ascii => '#',
+            _ => self.encode(u8::MAX),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Empty cell, full cell, and one dot at each bit `y * cols + x`.
+    const TABLE: [(Glyphs, char, char, &[char]); 5] = [
+        (
+            Glyphs::Braille,
+            '⠀',
+            '⣿',
+            &['⠁', '⠈', '⠂', '⠐', '⠄', '⠠', '⡀', '⢀'],
+        ),
+        (Glyphs::HalfBlock, ' ', '█', &['▀', '▄']),
+        (Glyphs::Quadrant, ' ', '█', &['▘', '▝', '▖', '▗']),
+        (
+            Glyphs::Sextant,
+            ' ',
+            '█',
+            &[
+                '\u{1FB00}',
+                '\u{1FB01}',
+                '\u{1FB03}',
+                '\u{1FB07}',
+                '\u{1FB0F}',
+                '\u{1FB1E}',
+            ],
+        ),
+        (Glyphs::Ascii, ' ', ':', &['\'', '.']),
+    ];
+
+    #[test]
+    fn empty_full_and_single_dots() {
+        for (glyphs, empty, full, singles) in TABLE {
+            assert_eq!(glyphs.encode(0), empty, "{glyphs:?}");
+            assert_eq!(glyphs.encode(u8::MAX), full, "{glyphs:?}");
+            assert_eq!(singles.len(), glyphs.cols() * glyphs.rows(), "{glyphs:?}");
+            for (bit, &want) in singles.iter().enumerate() {
+                assert_eq!(glyphs.encode(1 << bit), want, "{glyphs:?} bit {bit}");
+            }
+        }
+    }
+
+    #[test]
+    fn sextants_skip_the_half_blocks() {
+        let s = Glyphs::Sextant;
+        assert_eq!(s.encode(SEXTANT_LEFT), '▌');
+        assert_eq!(s.encode(SEXTANT_RIGHT), '▐');
+        assert_eq!(s.encode(SEXTANT_LEFT - 1), '\u{1FB13}');
+        assert_eq!(s.encode(SEXTANT_LEFT + 1), '\u{1FB14}');
+        assert_eq!(s.encode(SEXTANT_RIGHT - 1), '\u{1FB27}');
+        assert_eq!(s.encode(SEXTANT_RIGHT + 1), '\u{1FB28}');
+        assert_eq!(s.encode(0b11_1110), '\u{1FB3B}');
+    }
+
+    #[test]
+    fn every_mask_is_distinct() {
+        for (glyphs, ..) in TABLE {
+            let full = u8::MAX >> (8 - glyphs.cols() * glyphs.rows());
+            let mut seen: Vec<char> = (0..=full).map(|m| glyphs.encode(m)).collect();
+            seen.sort_unstable();
+            seen.dedup();
+            assert_eq!(seen.len(), usize::from(full) + 1, "{glyphs:?}");
+        }
+    }
+}
@@ -6,15 +6,19 @@ pub mod config;
 pub mod constants;
 pub mod data;
 pub mod error;
+pub mod glyphs;
 pub mod rng;
+pub mod time;
 
 // re-export frequently-used items for convenience
-pub use bounds::Axis;
-pub use color::{AnsiCode, ColorError, colorize};
-pub use config::{Config, ConfigBuilder};
+pub use bounds::{Axis, Scale};
+pub use color::{AnsiCode, ColorError, ColorMode, colorize};
+pub use config::{Baseline, Config, ConfigBuilder, Extent, Overlap, PlotStyle};
 pub use constants::{
-    BORDER_WIDTH, BRAILLE_HORIZONTAL_RESOLUTION, DECIMAL_PRECISION, LABEL_GUTTER, MIN_GRAPH_HEIGHT,
-    MIN_GRAPH_WIDTH,
+    BORDER_WIDTH, BRAILLE_HORIZONTAL_RESOLUTION, DECIMAL_PRECISION, FRAME_ROWS, LABEL_GUTTER,
+    MIN_GRAPH_HEIGHT, MIN_GRAPH_WIDTH, Y_TICK_TARGET,
 };
-pub use data::DataTimeStep;
+pub use data::{ColumnRef, ColumnSpec, CsvTable, DataTimeStep, Series};
 pub use error::{ConfigError, GraphError};
+pub use glyphs::Glyphs;
+pub use time::TimeFormat;
@@ -17,7 +17,7 @@ impl Lcg {
         Self(
             SystemTime::now()
                 .duration_since(UNIX_EPOCH)
-                .unwrap()
+                .unwrap_or_default()
                 .as_nanos() as u64,
         )
     }
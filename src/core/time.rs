@@ -0,0 +1,447 @@
+//! Time-axis formatting: plain numbers, durations and calendar dates.
+//!
+//! Timestamps are plain `f64`s everywhere else in the crate; for the calendar
+//! formats they are interpreted as seconds since the Unix epoch (UTC).
+
+use std::fmt::Write;
+
+use crate::core::bounds::{nice_step, ticks};
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 3600.0;
+const DAY: f64 = 86_400.0;
+
+/// Steps (in seconds) considered for duration and sub-day calendar ticks.
+const CLOCK_STEPS: [f64; 19] = [
+    1.0,
+    2.0,
+    5.0,
+    10.0,
+    15.0,
+    30.0,
+    MINUTE,
+    2.0 * MINUTE,
+    5.0 * MINUTE,
+    10.0 * MINUTE,
+    15.0 * MINUTE,
+    30.0 * MINUTE,
+    HOUR,
+    2.0 * HOUR,
+    3.0 * HOUR,
+    6.0 * HOUR,
+    12.0 * HOUR,
+    DAY,
+    7.0 * DAY,
+];
+
+/// How x values (the time column) are displayed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TimeFormat {
+    /// Raw numbers.
+    #[default]
+    Number,
+    /// Elapsed seconds, shown as `1h30m`, `45s`, …
+    Duration,
+    /// Epoch seconds, shown as calendar dates.
+    Date,
+    /// Epoch seconds, shown as dates and wall-clock times.
+    DateTime,
+}
+
+/// Tick spacing chosen by [`TimeFormat::ticks`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TickStep {
+    Seconds(f64),
+    Months(u32),
+}
+
+impl TimeFormat {
+    /// Round tick positions inside `[lo, hi]`, at most roughly `max_ticks`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // tick counts are tiny
+    pub fn ticks(self, lo: f64, hi: f64, max_ticks: usize) -> (Vec<f64>, TickStep) {
+        let max_ticks = max_ticks.max(1);
+        let span = hi - lo;
+        if span.is_nan() || span <= 0.0 {
+            return (vec![lo], TickStep::Seconds(0.0));
+        }
+        let raw = span / max_ticks as f64;
+
+        let step = match self {
+            Self::Number => nice_step(span, max_ticks),
+            Self::Duration => {
+                clock_step(raw).unwrap_or_else(|| DAY * nice_step(span / DAY, max_ticks))
+            }
+            Self::Date | Self::DateTime => {
+                let min = if self == Self::Date { DAY } else { 0.0 };
+                match clock_step(raw.max(min)) {
+                    Some(s) => s,
+                    None => return month_ticks(lo, hi, raw),
+                }
+            }
+        };
+        (ticks(lo, hi, step), TickStep::Seconds(step))
+    }
+
+    /// Render one tick label.  `step` controls the precision shown.
+    #[must_use]
+    pub fn label(self, t: f64, step: TickStep) -> String {
+        match (self, step) {
+            (Self::Number, TickStep::Seconds(s)) => format_number(t, s),
+            (Self::Duration, TickStep::Seconds(s)) => format_duration(t, s),
+            (Self::Number | Self::Duration, TickStep::Months(_)) => format_number(t, 1.0),
+            (Self::Date | Self::DateTime, _) => format_calendar(t, step),
+        }
+    }
+
+    /// A single instant at full precision, e.g. for a cursor readout.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // epoch seconds; `as` saturates
+    pub fn exact(self, t: f64) -> String {
+        match self {
+            Self::Number => format!("{t}"),
+            Self::Duration => format_duration(t, 1.0),
+            Self::Date | Self::DateTime => {
+                let date = format_calendar(t, TickStep::Seconds(DAY));
+                if (t.floor() as i64).rem_euclid(86_400) == 0 {
+                    date
+                } else {
+                    format!("{date} {}", format_calendar(t, TickStep::Seconds(1.0)))
+                }
+            }
+        }
+    }
+}
+
+/// Smallest clock-friendly step ≥ `raw`, or `None` above one week.
+fn clock_step(raw: f64) -> Option<f64> {
+    if raw < 1.0 {
+        return Some(nice_step(raw, 1));
+    }
+    CLOCK_STEPS.iter().copied().find(|&s| s >= raw)
+}
+
+/// Ticks on the first day of every `k`-th month (or year).
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)] // month counts and epoch days of plottable dates
+fn month_ticks(lo: f64, hi: f64, raw: f64) -> (Vec<f64>, TickStep) {
+    let months = (raw / (30.44 * DAY)).ceil().max(1.0) as u32;
+    let k = match months {
+        1 => 1,
+        2 => 2,
+        3 => 3,
+        4..=6 => 6,
+        7..=12 => 12,
+        m => 12 * nice_step(f64::from(m) / 12.0, 1).ceil() as u32,
+    };
+
+    let (y, m, _) = civil_from_days((lo / DAY).floor() as i64);
+    // absolute month number, rounded up to a multiple of k
+    let mut abs = y * 12 + i64::from(m) - 1;
+    abs += (i64::from(k) - abs.rem_euclid(i64::from(k))) % i64::from(k);
+
+    let mut out = Vec::new();
+    loop {
+        let t =
+            days_from_civil(abs.div_euclid(12), (abs.rem_euclid(12) + 1) as u32, 1) as f64 * DAY;
+        if t > hi {
+            break;
+        }
+        if t >= lo {
+            out.push(t);
+        }
+        abs += i64::from(k);
+    }
+    (out, TickStep::Months(k))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // `step` in (0, 1) gives a small positive digit count
+fn format_number(t: f64, step: f64) -> String {
+    let decimals = if step > 0.0 && step < 1.0 {
+        (-step.log10().floor()) as usize
+    } else {
+        0
+    };
+    format!("{t:.decimals$}")
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // `t` is non-negative here; `as` saturates
+fn format_duration(t: f64, step: f64) -> String {
+    let mut s = String::new();
+    if t < 0.0 {
+        s.push('-');
+    }
+    let t = t.abs();
+    if step < 1.0 {
+        let _ = write!(s, "{}s", format_number(t, step));
+        return s;
+    }
+    let mut rest = t.round() as u64;
+    for (unit, suffix) in [(86_400, 'd'), (3600, 'h'), (60, 'm'), (1, 's')] {
+        if rest >= unit || (rest == 0 && unit == 1 && s.len() <= 1) {
+            let _ = write!(s, "{}{suffix}", rest / unit);
+            rest %= unit;
+        }
+    }
+    s
+}
+
+#[allow(clippy::cast_possible_truncation)] // epoch seconds; `as` saturates
+fn format_calendar(t: f64, step: TickStep) -> String {
+    let secs = t.floor() as i64;
+    let tod = secs.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    let (hh, mm, ss) = (tod / 3600, tod % 3600 / 60, tod % 60);
+
+    match step {
+        TickStep::Months(k) if k % 12 == 0 => format!("{year}"),
+        TickStep::Months(_) => format!("{year}-{month:02}"),
+        TickStep::Seconds(sec) if sec >= DAY => format!("{year}-{month:02}-{day:02}"),
+        TickStep::Seconds(_) if tod == 0 => format!("{month:02}-{day:02}"),
+        TickStep::Seconds(sec) if sec >= MINUTE => format!("{hh:02}:{mm:02}"),
+        TickStep::Seconds(_) => format!("{hh:02}:{mm:02}:{ss:02}"),
+    }
+}
+
+// --- Calendar math (proleptic Gregorian, H. Hinnant's algorithms) ---
+
+/// Days since 1970-01-01 for a civil date.
+#[must_use]
+pub fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = i64::from(m);
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(d) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Civil date `(year, month, day)` for days since 1970-01-01.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // month 1..=12, day 1..=31
+pub fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+// --- Timestamp parsing ---
+
+/// Parse an ISO-8601 / RFC 3339 style timestamp into Unix epoch seconds.
+///
+/// Accepted shapes (`T` may also be a space, `/` may replace `-`):
+/// * `2024-03` and `2024-03-01`                          → [`TimeFormat::Date`]
+/// * `2024-03-01T12:00`, `…T12:00:00`, `…T12:00:00.250`  → [`TimeFormat::DateTime`]
+///
+/// Date-times may end in `Z` or a `±HH[:MM]` offset; without one they are
+/// taken as UTC.
+#[must_use]
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)] // validated month and day; epoch days of 4-digit years
+pub fn parse_timestamp(b: &[u8]) -> Option<(f64, TimeFormat)> {
+    let mut p = Cursor { b, i: 0 };
+
+    let year = p.digits(4)?;
+    let sep = p.any(b"-/")?;
+    let month = p.digits(2)?;
+    let day = if p.peek() == Some(sep) {
+        p.i += 1;
+        p.digits(2)?
+    } else {
+        1
+    };
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+    let days = days_from_civil(year, month as u32, day as u32);
+    if p.done() {
+        return Some((days as f64 * DAY, TimeFormat::Date));
+    }
+
+    p.any(b"T ")?;
+    let hh = p.digits(2)?;
+    p.any(b":")?;
+    let mm = p.digits(2)?;
+    let mut secs = 0.0;
+    if p.peek() == Some(b':') {
+        p.i += 1;
+        let start = p.i;
+        p.digits(2)?;
+        if p.peek() == Some(b'.') || p.peek() == Some(b',') {
+            p.i += 1;
+            while p.peek().is_some_and(|c| c.is_ascii_digit()) {
+                p.i += 1;
+            }
+        }
+        let text = std::str::from_utf8(&b[start..p.i]).ok()?.replace(',', ".");
+        secs = text.parse::<f64>().ok()?;
+    }
+    if hh > 23 || mm > 59 || secs >= 61.0 {
+        return None;
+    }
+
+    let offset = match p.peek() {
+        None => 0,
+        Some(b'Z' | b'z') => {
+            p.i += 1;
+            0
+        }
+        Some(sign @ (b'+' | b'-')) => {
+            p.i += 1;
+            let oh = p.digits(2)?;
+            if p.peek() == Some(b':') {
+                p.i += 1;
+            }
+            let om = if p.done() { 0 } else { p.digits(2)? };
+            if oh > 23 || om > 59 {
+                return None;
+            }
+            let off = oh * 3600 + om * 60;
+            if sign == b'+' { off } else { -off }
+        }
+        Some(_) => return None,
+    };
+    if !p.done() {
+        return None;
+    }
+
+    let t = days as f64 * DAY + (hh * 3600 + mm * 60 - offset) as f64 + secs;
+    Some((t, TimeFormat::DateTime))
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Byte cursor for [`parse_timestamp`].
+struct Cursor<'a> {
+    b: &'a [u8],
+    i: usize,
+}
+
+impl Cursor<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.b.get(self.i).copied()
+    }
+    fn done(&self) -> bool {
+        self.i == self.b.len()
+    }
+    /// Exactly `n` ASCII digits.
+    fn digits(&mut self, n: usize) -> Option<i64> {
+        let s = self.b.get(self.i..self.i + n)?;
+        if !s.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.i += n;
+        Some(s.iter().fold(0, |acc, &d| acc * 10 + i64::from(d - b'0')))
+    }
+    /// One of `set`; returns the byte matched.
+    fn any(&mut self, set: &[u8]) -> Option<u8> {
+        let c = self.peek().filter(|c| set.contains(c))?;
+        self.i += 1;
+        Some(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Option<f64> {
+        parse_timestamp(s.as_bytes()).map(|(t, _)| t)
+    }
+
+    #[test]
+    fn civil_days_round_trip() {
+        for z in -800_000..800_000 {
+            let (y, m, d) = civil_from_days(z);
+            assert_eq!(days_from_civil(y, m, d), z, "{y}-{m}-{d}");
+        }
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(19_783), (2024, 3, 1));
+    }
+
+    #[test]
+    fn leap_years() {
+        for (y, leap) in [(2024, true), (2023, false), (2000, true), (1900, false)] {
+            let feb = days_from_civil(y, 3, 1) - days_from_civil(y, 2, 1);
+            assert_eq!(feb, if leap { 29 } else { 28 }, "{y}");
+            assert_eq!(parse(&format!("{y}-02-29")).is_some(), leap, "{y}");
+        }
+    }
+
+    #[test]
+    fn offsets() {
+        let utc = parse("2024-03-01T12:00:00").unwrap();
+        assert_eq!(parse("2024-03-01T12:00:00Z"), Some(utc));
+        assert_eq!(parse("2024-03-01T12:00:00z"), Some(utc));
+        assert_eq!(parse("2024-03-01T14:30:00+02:30"), Some(utc));
+        assert_eq!(parse("2024-03-01T14:30:00+0230"), Some(utc));
+        assert_eq!(parse("2024-03-01T14:00+02"), Some(utc));
+        assert_eq!(parse("2024-03-01 09:15-02:45"), Some(utc));
+        assert_eq!(parse("2024-03-01T09:15:00-0245"), Some(utc));
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        let whole = parse("2024-03-01T12:00:01").unwrap();
+        assert_eq!(parse("2024-03-01T12:00:01.250"), Some(whole + 0.25));
+        assert_eq!(parse("2024-03-01T12:00:01,250"), Some(whole + 0.25));
+        assert_eq!(parse("2024-03-01T12:00:01,5Z"), Some(whole + 0.5));
+    }
+
+    #[test]
+    fn shapes_and_formats() {
+        let day = parse_timestamp(b"2024-03-01").unwrap();
+        assert_eq!(day, (19_783.0 * DAY, TimeFormat::Date));
+        assert_eq!(
+            parse_timestamp(b"2024/03"),
+            Some((19_783.0 * DAY, TimeFormat::Date))
+        );
+        let (t, kind) = parse_timestamp(b"2024-03-01T00:00").unwrap();
+        assert_eq!((t, kind), (day.0, TimeFormat::DateTime));
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        for s in [
+            "",
+            "2024",
+            "24-03-01",
+            "2024-13-01",
+            "2024-00-01",
+            "2023-02-29",
+            "2024-04-31",
+            "2024-03-01T24:00",
+            "2024-03-01T12:60",
+            "2024-03-01T12:00:61",
+            "2024-03-01T12",
+            "2024-03-01T12:00+24:00",
+            "2024-03-01T12:00+01:60",
+            "2024-03-01T12:00+1",
+            "2024-03-01T12:00 UTC",
+            "2024-03-01T12:00Zx",
+            "2024-03/01",
+        ] {
+            assert_eq!(parse(s), None, "{s:?}");
+        }
+    }
+}
@@ -5,17 +5,28 @@ pub mod core;
 pub mod render;
 
 pub use core::{
-    color::{AnsiCode, ColorError, colorize},
-    config::{Config, ConfigBuilder},
+    bounds::Scale,
+    color::{AnsiCode, ColorError, ColorMode, colorize},
+    config::{Baseline, Config, ConfigBuilder, Extent, Overlap, PlotStyle},
     constants::{DECIMAL_PRECISION, MIN_GRAPH_HEIGHT, MIN_GRAPH_WIDTH},
-    data::DataTimeStep,
+    data::{ColumnRef, ColumnSpec, CsvTable, DataTimeStep, Series, read_csv_table},
     error::{ConfigError, GraphError},
+    glyphs::Glyphs,
 };
 
-pub use render::{Binner, Renderer, Strategy, preprocess_to_braille};
+pub use render::{
+    Aggregate, BinRule, Binner, Histogram, Layout, Panel, Renderer, Source, Strategy, build_frame,
+    build_sparkline, compose, preprocess_series, preprocess_to_braille,
+};
 
 /// Convenience function kept for backwards compatibility.  Plots a **static**
 /// in-memory data set with automatic axis scaling.
+///
+/// # Errors
+///
+/// [`GraphError`] when `data` is empty, the terminal is too small or the
+/// frame cannot be written.
+#[allow(clippy::needless_pass_by_value)] // signature is part of the stable API
 pub fn plot_data(
     data: Vec<DataTimeStep>,
     title: &str,
@@ -32,7 +43,7 @@ pub fn plot_data(
     let (x_min, x_max) = Axis::X.bounds(&data);
 
     let term = terminal_geometry();
-    let (x_chars, y_chars) = graph_dims(term, data.len());
+    let (x_chars, y_chars) = graph_dims(term, data.len(), Glyphs::Braille);
 
     let cfg = Config::builder(x_chars, y_chars)
         .title(title)
@@ -42,7 +53,83 @@ pub fn plot_data(
         .build()?;
 
     let mut binner = Binner::new(Strategy::Index);
-    let binned = binner.bin(&data, &cfg);
-    let plot = preprocess_to_braille(&binned, &cfg, cumulative)?;
+    let steps = binner.bin(&data, &cfg);
+    let mut plot = preprocess_to_braille(&steps, &cfg, cumulative)?;
+    plot.x_span = binner.span();
+    Renderer::full().render(&cfg, &plot)
+}
+
+/// Plot the distribution of `values` as vertical bars, with their count,
+/// min, mean, [`SUMMARY_PERCENTILES`](render::hist::SUMMARY_PERCENTILES)
+/// (p50/p90/p99) and max in the subtitle.
+///
+/// # Errors
+///
+/// As [`plot_data`], and when no value is finite.
+pub fn plot_histogram(
+    values: &[f64],
+    rule: BinRule,
+    title: &str,
+    color: AnsiCode,
+    glyphs: Glyphs,
+) -> Result<(), GraphError> {
+    use core::bounds::{graph_dims, terminal_geometry};
+    use render::hist::SUMMARY_PERCENTILES;
+
+    let (x_chars, y_chars) = graph_dims(terminal_geometry(), usize::MAX, glyphs);
+    let hist = Histogram::new(values, rule, x_chars * glyphs.cols())?;
+
+    let cfg = Config::builder(x_chars, y_chars)
+        .title(title)
+        .subtitle(hist.summary(&SUMMARY_PERCENTILES))
+        .color(color)
+        .y_range(0.0..=hist.peak(false))
+        .style(PlotStyle::Area(Baseline::Zero))
+        .glyphs(glyphs)
+        .build()?;
+
+    let mut plot = preprocess_to_braille(&hist.steps(cfg.dot_columns(), false), &cfg, false)?;
+    plot.x_span = Some((hist.lo, hist.hi));
     Renderer::full().render(&cfg, &plot)
 }
+
+/// `values` as a bare `width`×`height` character sparkline (see
+/// [`build_sparkline`]), binned by index and drawn as a line.
+///
+/// # Errors
+///
+/// [`GraphError::EmptyData`] when `values` is empty, and
+/// [`ConfigError::ZeroSize`] when `width` or `height` is zero.
+#[allow(clippy::cast_precision_loss)] // value counts are far below 2^52
+pub fn sparkline(values: &[f64], width: usize, height: usize) -> Result<String, GraphError> {
+    use core::bounds::Axis;
+
+    if width == 0 || height == 0 {
+        return Err(ConfigError::ZeroSize { width, height }.into());
+    }
+
+    let data: Vec<DataTimeStep> = values
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| DataTimeStep {
+            time: i as f64,
+            min: v,
+            max: v,
+        })
+        .collect();
+    if data.is_empty() {
+        return Err(GraphError::EmptyData);
+    }
+
+    let (y_min, y_max) = Axis::Y.bounds(&data);
+    let cfg = Config::builder(width, height)
+        .y_range(y_min..=y_max)
+        .x_range(0.0, (data.len() - 1) as f64)
+        .style(PlotStyle::Line)
+        .colored(false)
+        .build()?;
+
+    let binned = Binner::new(Strategy::Index).bin(&data, &cfg);
+    let plot = preprocess_to_braille(&binned, &cfg, false)?;
+    Ok(build_sparkline(&cfg, &plot))
+}
@@ -1,3 +1,6 @@
-fn main() -> Result<(), braille_graph::core::error::GraphError> {
-    braille_graph::cli::run()
+fn main() {
+    if let Err(e) = braille_graph::cli::run() {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
 }
@@ -1,41 +1,93 @@
 //! Incremental binning with data passed **by reference**.
 //!
-//! Cached buckets, O(1) update on scroll.
-//! * Strategy::Index   - split by index
-//! * Strategy::Time    - split by time
+//! Index and time buckets are cached and updated in O(1) on a scroll.
+//! * `Strategy::Index` - split by index
+//! * `Strategy::Time`  - split by time
+//! * `Strategy::Lttb`  - one representative sample per bucket (LTTB),
+//!   re-picked on every call
+//!
+//! Index and time buckets are drawn as their min/max envelope unless an
+//! [`Aggregate`] asks for a central value plus a band.
+//!
+//! A series that only grows (samples appended, none dropped) is folded into
+//! fine buckets that merge pairwise as they fill up, so a live plot of the
+//! full history never rebuilds from scratch.
 //!
 //! Call pattern for smooth scrolling:
 //! ```rust
+//! # use braille_graph::{Binner, Config, DataTimeStep, Strategy};
+//! # let config = Config::builder(40, 10).y_range(-1.0..=1.0).build().unwrap();
+//! # let step = |t: f64| DataTimeStep { time: t, min: t.sin(), max: t.sin() };
+//! # let mut data: Vec<_> = (0..500).map(|i| step(f64::from(i))).collect();
 //! // once
 //! let mut binner = Binner::new(Strategy::Index);
 //!
-//! loop {
-//!     data.remove(0);              // drop oldest
-//!     data.push(new_step);         // push newest
+//! for t in 500..600 {
+//!     data.remove(0);                  // drop oldest
+//!     data.push(step(f64::from(t)));   // push newest
 //!     let binned = binner.bin(&data, &config);
 //!     // render ...
+//! #   assert!(!binned.is_empty());
 //! }
 //! ```
 
-use crate::core::{
-    config::Config, constants::BRAILLE_HORIZONTAL_RESOLUTION as HR, data::DataTimeStep,
-};
+use super::braille::gaps;
+use crate::core::{config::Config, data::DataTimeStep};
+
+/// Fine buckets kept per output bucket while appending (between this and
+/// twice this).  Output bucket edges are off by less than `1 / FINE_PER_BIN`
+/// of a dot column.
+const FINE_PER_BIN: usize = 8;
 
 /// Selectable algorithm.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum Strategy {
+    #[default]
     Index,
     Time,
+    /// Largest-Triangle-Three-Buckets: a shape-preserving line instead of
+    /// the min/max envelope.
+    Lttb,
+}
+
+/// What each index / time bucket is reduced to.  Samples are taken at the
+/// middle of their own min/max.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Aggregate {
+    /// Min/max envelope, no central value.
+    #[default]
+    Extrema,
+    /// Mean, with a band of ± one standard deviation.
+    MeanStd,
+    /// Median, with a band between two percentiles (0–100).
+    Percentile { lo: f64, hi: f64 },
+}
+
+/// The samples behind one step returned by [`Binner::bin`], as indices into
+/// the `data` it was given.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Source {
+    /// Samples `start..end` fell into the bucket (none for a blank one).
+    pub start: usize,
+    pub end: usize,
+    /// Sample with the smallest `min` / largest `max` (the pick for LTTB).
+    pub min_index: usize,
+    pub max_index: usize,
 }
 
-impl Default for Strategy {
-    fn default() -> Self {
-        Self::Index
+impl Source {
+    fn single(i: usize) -> Self {
+        Self {
+            start: i,
+            end: i + 1,
+            min_index: i,
+            max_index: i,
+        }
     }
 }
 
 /// Cached metadata for one bucket.
-#[derive(Clone)]
+#[derive(Clone, Default)]
 struct Bucket {
     start: usize, // inclusive
     end: usize,   // exclusive
@@ -43,6 +95,11 @@ struct Bucket {
     max: f64,
     min_index: usize,
     max_index: usize,
+    // Aggregate::MeanStd running sums
+    sum: f64,
+    sum_sq: f64,
+    /// Band low, central value, band high (for non-`Extrema` aggregates).
+    band: [f64; 3],
 }
 
 /// Stateful binning engine.
@@ -56,12 +113,21 @@ pub struct Binner {
     prev_first_t: Option<f64>, // to detect scroll
     prev_last_t: Option<f64>,
     win: Option<f64>, // size of x_tick
+    agg: Aggregate,
+    scratch: Vec<f64>, // percentile selection
+    // append-only growth
+    fine: Vec<Bucket>,
+    fine_width: f64,   // samples (index) or seconds (time) per fine bucket
+    fine_t0: f64,      // time of the first sample
+    clip_start: usize, // samples before x_range, for `sources`
+    span: Option<(f64, f64)>,
 }
 
 impl Binner {
     // --- Helpers ---
 
     #[inline]
+    #[must_use]
     pub fn new(strat: Strategy) -> Self {
         Self {
             strat,
@@ -73,6 +139,97 @@ impl Binner {
             prev_first_t: None,
             prev_last_t: None,
             win: None,
+            agg: Aggregate::Extrema,
+            scratch: Vec::new(),
+            fine: Vec::new(),
+            fine_width: 0.0,
+            fine_t0: 0.0,
+            clip_start: 0,
+            span: None,
+        }
+    }
+
+    /// Reduce buckets with `agg` instead of their min/max (ignored by
+    /// [`Strategy::Lttb`]).
+    #[inline]
+    #[must_use]
+    pub fn aggregate(mut self, agg: Aggregate) -> Self {
+        self.agg = agg;
+        self
+    }
+
+    /// Sample value used by the aggregates.
+    #[inline]
+    fn value(p: &DataTimeStep) -> f64 {
+        0.5 * (p.min + p.max)
+    }
+
+    /// Recompute `bucket`'s running sums and band from scratch.
+    fn summarize(
+        agg: Aggregate,
+        scratch: &mut Vec<f64>,
+        bucket: &mut Bucket,
+        data: &[DataTimeStep],
+    ) {
+        let slice = &data[bucket.start..bucket.end];
+        match agg {
+            Aggregate::Extrema => {}
+            Aggregate::MeanStd => {
+                bucket.sum = slice.iter().map(Self::value).sum();
+                bucket.sum_sq = slice.iter().map(|p| Self::value(p).powi(2)).sum();
+                Self::mean_std_band(bucket, slice.len());
+            }
+            Aggregate::Percentile { lo, hi } => {
+                if slice.is_empty() {
+                    return;
+                }
+                scratch.clear();
+                scratch.extend(slice.iter().map(Self::value));
+                bucket.band = [
+                    percentile(scratch, lo),
+                    percentile(scratch, 50.0),
+                    percentile(scratch, hi),
+                ];
+            }
+        }
+    }
+
+    /// Add the newest sample to `bucket` (already included in its range).
+    fn summarize_push(&mut self, i: usize, data: &[DataTimeStep]) {
+        let b = &mut self.buckets[i];
+        if let Aggregate::MeanStd = self.agg {
+            let x = Self::value(&data[b.end - 1]);
+            b.sum += x;
+            b.sum_sq += x * x;
+            Self::mean_std_band(b, b.end - b.start);
+        } else {
+            Self::summarize(self.agg, &mut self.scratch, b, data);
+        }
+    }
+
+    fn summarize_all(&mut self, data: &[DataTimeStep]) {
+        for b in &mut self.buckets {
+            Self::summarize(self.agg, &mut self.scratch, b, data);
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)] // sample counts are far below 2^52
+    fn mean_std_band(bucket: &mut Bucket, n: usize) {
+        if n == 0 {
+            return;
+        }
+        let n = n as f64;
+        let mean = bucket.sum / n;
+        let sd = (bucket.sum_sq / n - mean * mean).max(0.0).sqrt();
+        bucket.band = [mean - sd, mean, mean + sd];
+    }
+
+    /// Band edges if aggregating, min/max otherwise.
+    #[inline]
+    fn band(&self, b: &Bucket) -> (f64, f64) {
+        match self.agg {
+            Aggregate::Extrema => (b.min, b.max),
+            _ => (b.band[0], b.band[2]),
         }
     }
 
@@ -80,8 +237,7 @@ impl Binner {
     fn recompute_extrema(bucket: &mut Bucket, data: &[DataTimeStep]) {
         bucket.min = f64::INFINITY;
         bucket.max = f64::NEG_INFINITY;
-        for index in bucket.start..bucket.end {
-            let p = &data[index];
+        for (index, p) in data.iter().enumerate().take(bucket.end).skip(bucket.start) {
             if p.min < bucket.min {
                 bucket.min = p.min;
                 bucket.min_index = index;
@@ -93,14 +249,28 @@ impl Binner {
         }
     }
 
+    /// Was `data` produced from the previous call's data by dropping the
+    /// oldest sample and pushing a new one?
+    #[allow(clippy::float_cmp)] // exact identity of stored timestamps
+    fn scrolled_one(&self, data: &[DataTimeStep]) -> bool {
+        let n = data.len();
+        n >= 2
+            && n == self.last_len
+            && self.prev_first_t.is_some_and(|prev| prev != data[0].time)
+            && self
+                .prev_last_t
+                .is_some_and(|prev| prev == data[n - 2].time)
+    }
+
     fn emit(&self, data: &[DataTimeStep]) -> Vec<DataTimeStep> {
         let mut out = Vec::with_capacity(self.buckets.len());
         for b in &self.buckets {
             let mid = b.start + (b.end - b.start) / 2;
+            let (min, max) = self.band(b);
             out.push(DataTimeStep {
                 time: data[mid].time,
-                min: b.min,
-                max: b.max,
+                min,
+                max,
             });
         }
         out
@@ -134,14 +304,7 @@ impl Binner {
             return self.build_full_index(data);
         }
 
-        // Detect a one-step scroll with the same length.
-        let scrolled_one = n == self.last_len
-            && self.prev_first_t.map_or(false, |prev| prev != data[0].time)
-            && self
-                .prev_last_t
-                .map_or(false, |prev| prev == data[n - 2].time);
-
-        if !scrolled_one {
+        if !self.scrolled_one(data) {
             return self.build_full_index(data);
         }
 
@@ -186,6 +349,14 @@ impl Binner {
             }
         }
 
+        // Aggregates: the first bucket lost a sample, the last gained one.
+        if self.agg != Aggregate::Extrema {
+            Self::summarize(self.agg, &mut self.scratch, &mut self.buckets[0], data);
+            if self.buckets.len() > 1 {
+                self.summarize_push(self.buckets.len() - 1, data);
+            }
+        }
+
         self.prev_first_t = Some(data[0].time);
         self.prev_last_t = Some(data[n - 1].time);
         self.last_len = n;
@@ -227,9 +398,11 @@ impl Binner {
                 max: high,
                 min_index: low_index,
                 max_index: high_index,
+                ..Bucket::default()
             });
         }
 
+        self.summarize_all(data);
         self.cached = true;
         self.last_len = n;
         self.prev_first_t = Some(data[0].time);
@@ -239,9 +412,10 @@ impl Binner {
 
     // --- Uniform Time Binning ---
 
+    #[allow(clippy::cast_precision_loss)] // bucket counts are far below 2^52
     fn bin_time(&mut self, data: &[DataTimeStep], config: &Config) -> Vec<DataTimeStep> {
         let n = data.len();
-        let target = config.x_chars * HR;
+        let target = config.dot_columns();
 
         // Full rebuild triggers
         let need_full = !self.cached
@@ -250,8 +424,14 @@ impl Binner {
             || config.x_range != self.last_xrange;
 
         if need_full {
-            let t_lo = data.first().unwrap().time;
-            let t_hi = data.last().unwrap().time;
+            let Some((t_lo, t_hi)) = config
+                .x_range
+                .or_else(|| Some((data.first()?.time, data.last()?.time)))
+            else {
+                self.buckets.clear();
+                self.last_len = 0;
+                return Vec::new();
+            };
             let win = (t_hi - t_lo) / target as f64;
 
             self.cached = true;
@@ -262,7 +442,7 @@ impl Binner {
             self.prev_first_t = Some(t_lo);
             self.prev_last_t = Some(t_hi);
 
-            return self.build_full_time(data, win);
+            return self.build_full_time(data, t_lo, t_hi);
         }
 
         // --- Incremental Path ---
@@ -338,12 +518,14 @@ impl Binner {
         }
 
         // 3. fix leftmost bucket if it lost extrema due to the global shift
-        if let Some(first) = self.buckets.first_mut() {
-            if first.min_index < first.start || first.max_index < first.start {
-                Self::recompute_extrema(first, data);
-            }
+        if let Some(first) = self.buckets.first_mut()
+            && (first.min_index < first.start || first.max_index < first.start)
+        {
+            Self::recompute_extrema(first, data);
         }
 
+        self.summarize_all(data);
+
         // 4 · update bookkeeping & emit
         self.prev_first_t = Some(t_lo_new);
         self.prev_last_t = Some(data.last().unwrap().time);
@@ -353,49 +535,55 @@ impl Binner {
 
     // --- Full Rebuild (Uniform Time) ---
 
-    fn build_full_time(&mut self, data: &[DataTimeStep], win: f64) -> Vec<DataTimeStep> {
+    /// Buckets `[t_lo + i·win, t_lo + (i+1)·win)`, the last one closed at
+    /// exactly `t_hi`.  Buckets without samples are NaN (blank) rather than
+    /// invented.
+    #[allow(clippy::cast_precision_loss)] // bucket counts are far below 2^52
+    fn build_full_time(
+        &mut self,
+        data: &[DataTimeStep],
+        t_lo: f64,
+        t_hi: f64,
+    ) -> Vec<DataTimeStep> {
         let target = self.target;
+        let win = (t_hi - t_lo) / target as f64;
 
         self.buckets.clear();
         let mut out: Vec<DataTimeStep> = Vec::with_capacity(target);
 
-        let mut window_low = data.first().unwrap().time;
+        let mut window_low = t_lo;
         let mut index = 0usize;
 
-        for _ in 0..target {
-            let window_high = window_low + win;
+        for i in 0..target {
+            // edges from `t_lo` each time: summing `win` drifts below `t_hi`
+            let closed = i + 1 == target;
+            let window_high = if closed {
+                t_hi
+            } else {
+                t_lo + (i + 1) as f64 * win
+            };
             let start = index;
 
-            let mut low = f64::INFINITY;
-            let mut high = f64::NEG_INFINITY;
+            let mut low = f64::NAN;
+            let mut high = f64::NAN;
             let mut low_index = start;
             let mut high_index = start;
 
-            while index < data.len() && data[index].time < window_high {
+            while index < data.len()
+                && (data[index].time < window_high || closed && data[index].time <= window_high)
+            {
                 let p = &data[index];
-                if p.min < low {
+                if index == start || p.min < low {
                     low = p.min;
                     low_index = index;
                 }
-                if p.max > high {
+                if index == start || p.max > high {
                     high = p.max;
                     high_index = index;
                 }
                 index += 1;
             }
 
-            if !low.is_finite() {
-                // Empty bucket - duplicate previous or fall back to current index
-                if let Some(prev) = out.last() {
-                    low = prev.min;
-                    high = prev.max;
-                } else {
-                    let p = &data[index.min(data.len() - 1)];
-                    low = p.min;
-                    high = p.max;
-                }
-            }
-
             self.buckets.push(Bucket {
                 start,
                 end: index,
@@ -403,6 +591,7 @@ impl Binner {
                 max: high,
                 min_index: low_index,
                 max_index: high_index,
+                ..Bucket::default()
             });
 
             out.push(DataTimeStep {
@@ -413,18 +602,382 @@ impl Binner {
 
             window_low = window_high;
         }
+
+        if self.agg != Aggregate::Extrema {
+            self.summarize_all(data);
+            for (b, step) in self.buckets.iter_mut().zip(&mut out) {
+                if b.end == b.start {
+                    b.band = [f64::NAN; 3];
+                }
+                step.min = b.band[0];
+                step.max = b.band[2];
+            }
+        }
+        out
+    }
+
+    // --- Largest-Triangle-Three-Buckets ---
+
+    /// Keeps the first and last sample and, from each bucket in between, the
+    /// sample spanning the largest triangle with the previous pick and the
+    /// average of the next bucket.
+    ///
+    /// Not incremental: every pick depends on the one before it and on the
+    /// next bucket's centroid, and a scroll shifts every bucket by a sample,
+    /// so all of them are re-picked on every call (O(n)).
+    fn bin_lttb(&mut self, data: &[DataTimeStep]) -> Vec<DataTimeStep> {
+        self.last_len = data.len(); // for `sources`
+        if data.is_empty() || self.target < 3 || data.len() <= self.target {
+            self.buckets.clear();
+            return data.to_vec();
+        }
+        self.build_full_lttb(data)
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )] // non-negative bucket bounds, far below 2^52
+    fn build_full_lttb(&mut self, data: &[DataTimeStep]) -> Vec<DataTimeStep> {
+        let n = data.len();
+        let inner = self.target - 2;
+        let win = (n - 2) as f64 / inner as f64;
+
+        self.buckets.clear();
+        self.buckets.reserve(inner);
+        for i in 0..inner {
+            let start = 1 + (i as f64 * win) as usize;
+            let end = if i + 1 == inner {
+                n - 1
+            } else {
+                1 + ((i + 1) as f64 * win) as usize
+            };
+            self.buckets.push(Bucket {
+                start,
+                end,
+                min_index: start,
+                max_index: start,
+                ..Bucket::default()
+            });
+        }
+        for i in 0..inner {
+            self.lttb_pick(i, data);
+        }
+        self.emit_lttb(data)
+    }
+
+    /// Re-pick bucket `i` against the previous pick and the next bucket's
+    /// centroid (the pinned first / last sample at the edges).
+    #[allow(clippy::cast_precision_loss)] // sample counts are far below 2^52
+    fn lttb_pick(&mut self, i: usize, data: &[DataTimeStep]) {
+        let mid = |p: &DataTimeStep| 0.5 * (p.min + p.max);
+
+        let a = i
+            .checked_sub(1)
+            .map_or(&data[0], |j| &data[self.buckets[j].min_index]);
+        let (cx, cy) = if let Some(next) = self.buckets.get(i + 1) {
+            let slice = &data[next.start..next.end];
+            let len = slice.len() as f64;
+            (
+                slice.iter().map(|p| p.time).sum::<f64>() / len,
+                slice.iter().map(mid).sum::<f64>() / len,
+            )
+        } else {
+            let p = &data[data.len() - 1];
+            (p.time, mid(p))
+        };
+        let (ax, ay) = (a.time, mid(a));
+
+        let b = &mut self.buckets[i];
+        let mut best = b.start;
+        let mut best_area = f64::NEG_INFINITY;
+        for (index, p) in data.iter().enumerate().take(b.end).skip(b.start) {
+            let area = ((ax - cx) * (mid(p) - ay) - (ax - p.time) * (cy - ay)).abs();
+            if area > best_area {
+                best_area = area;
+                best = index;
+            }
+        }
+        b.min_index = best;
+        b.max_index = best;
+        b.min = data[best].min;
+        b.max = data[best].max;
+    }
+
+    fn emit_lttb(&self, data: &[DataTimeStep]) -> Vec<DataTimeStep> {
+        let mut out = Vec::with_capacity(self.buckets.len() + 2);
+        out.push(data[0]);
+        out.extend(self.buckets.iter().map(|b| data[b.min_index]));
+        out.push(data[data.len() - 1]);
+        out
+    }
+
+    // --- Append-Only Growth ---
+
+    /// Is `data` the previous call's data with samples appended?
+    #[allow(clippy::float_cmp)] // exact identity of stored timestamps
+    fn appended(&self, data: &[DataTimeStep]) -> bool {
+        let old = self.last_len;
+        old > 0
+            && data.len() > old
+            && self.prev_first_t == Some(data[0].time)
+            && self.prev_last_t == Some(data[old - 1].time)
+    }
+
+    /// Amortized O(1) per appended sample: new samples go into the last fine
+    /// bucket (or open new ones); once there are more than
+    /// `2 × FINE_PER_BIN` fine buckets per output bucket, neighbours merge
+    /// pairwise.  Each frame then only combines fine buckets.
+    ///
+    /// Returns `None` when the fine buckets can't be seeded (zero time span).
+    #[allow(clippy::cast_precision_loss)] // sample counts are far below 2^52
+    fn bin_append(&mut self, data: &[DataTimeStep]) -> Option<Vec<DataTimeStep>> {
+        let n = data.len();
+        let from = if self.fine.is_empty() {
+            let fine = (FINE_PER_BIN * self.target) as f64;
+            self.fine_t0 = data[0].time;
+            self.fine_width = match self.strat {
+                Strategy::Time => (data[n - 1].time - data[0].time) / fine,
+                _ => (n as f64 / fine).floor().max(1.0),
+            };
+            if self.fine_width.is_nan() || self.fine_width <= 0.0 {
+                return None;
+            }
+            0
+        } else {
+            self.last_len
+        };
+
+        for i in from..n {
+            self.push_fine(i, data);
+        }
+        while self.fine.len() > 2 * FINE_PER_BIN * self.target {
+            self.halve_fine();
+        }
+
+        self.last_len = n;
+        self.prev_first_t = Some(data[0].time);
+        self.prev_last_t = Some(data[n - 1].time);
+        Some(self.emit_fine(data))
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )] // bucket position clamped to >= 0; `as` saturates
+    fn push_fine(&mut self, idx: usize, data: &[DataTimeStep]) {
+        let sample = &data[idx];
+        let pos = match self.strat {
+            Strategy::Time => (sample.time - self.fine_t0) / self.fine_width,
+            _ => idx as f64 / self.fine_width,
+        };
+        // samples arrive in order: never go back before the last bucket
+        let slot = (pos.max(0.0) as usize).max(self.fine.len().saturating_sub(1));
+        while self.fine.len() <= slot {
+            self.fine.push(Bucket {
+                start: idx,
+                end: idx,
+                ..Bucket::default()
+            });
+        }
+
+        let bucket = &mut self.fine[slot];
+        if bucket.end == bucket.start {
+            *bucket = Bucket {
+                start: idx,
+                end: idx,
+                min: sample.min,
+                max: sample.max,
+                min_index: idx,
+                max_index: idx,
+                ..Bucket::default()
+            };
+        }
+        if sample.min < bucket.min {
+            bucket.min = sample.min;
+            bucket.min_index = idx;
+        }
+        if sample.max > bucket.max {
+            bucket.max = sample.max;
+            bucket.max_index = idx;
+        }
+        let x = Self::value(sample);
+        bucket.sum += x;
+        bucket.sum_sq += x * x;
+        bucket.end = idx + 1;
+    }
+
+    /// Merge fine buckets pairwise and double their width.
+    fn halve_fine(&mut self) {
+        let merged: Vec<Bucket> = self
+            .fine
+            .chunks(2)
+            .map(|pair| pair.iter().skip(1).fold(pair[0].clone(), merge))
+            .collect();
+        self.fine = merged;
+        self.fine_width *= 2.0;
+    }
+
+    /// Combine the fine buckets into exactly `target` output buckets.
+    #[allow(clippy::cast_precision_loss)] // bucket counts are far below 2^52
+    fn emit_fine(&mut self, data: &[DataTimeStep]) -> Vec<DataTimeStep> {
+        let m = self.fine.len();
+        let t = self.target;
+        self.buckets.clear();
+        let mut out = Vec::with_capacity(t);
+        for j in 0..t {
+            let (lo, hi) = (j * m / t, ((j + 1) * m / t).max(j * m / t + 1).min(m));
+            let mut b = self.fine[lo..hi]
+                .iter()
+                .skip(1)
+                .fold(self.fine[lo].clone(), merge);
+            let len = b.end - b.start;
+            Self::mean_std_band(&mut b, len);
+
+            let (min, max) = if b.end > b.start {
+                self.band(&b)
+            } else {
+                (f64::NAN, f64::NAN)
+            };
+            let time = match self.strat {
+                Strategy::Time => self.fine_t0 + 0.5 * (lo + hi) as f64 * self.fine_width,
+                _ => data[b.start + (b.end - b.start) / 2].time,
+            };
+            out.push(DataTimeStep { time, min, max });
+            if b.end == b.start {
+                b.band = [f64::NAN; 3];
+            }
+            self.buckets.push(b);
+        }
         out
     }
 
     // --- API ---
 
+    /// [`Binner::bin`] plus one central value per emitted step (mean or
+    /// median, as `min == max`).  The centre is empty for
+    /// [`Aggregate::Extrema`] and [`Strategy::Lttb`].
+    pub fn bin_with_center(
+        &mut self,
+        data: &[DataTimeStep],
+        config: &Config,
+    ) -> (Vec<DataTimeStep>, Vec<DataTimeStep>) {
+        let band = self.bin(data, config);
+        if self.agg == Aggregate::Extrema || self.strat == Strategy::Lttb {
+            return (band, Vec::new());
+        }
+        let point = |time: f64, v: f64| DataTimeStep {
+            time,
+            min: v,
+            max: v,
+        };
+        let center = if self.buckets.len() == band.len() {
+            band.iter()
+                .zip(&self.buckets)
+                .map(|(p, b)| point(p.time, b.band[1]))
+                .collect()
+        } else {
+            // too few samples to bin: every sample is its own centre
+            band.iter().map(|p| point(p.time, Self::value(p))).collect()
+        };
+        (band, center)
+    }
+
+    /// Time range the last [`bin`](Self::bin) covered: `config.x_range` for
+    /// time buckets, otherwise the first and last binned sample.  The steps
+    /// are bucket centres inside it, so this, not their own span, is what
+    /// an envelope's x axis should be labelled with.
+    #[must_use]
+    pub fn span(&self) -> Option<(f64, f64)> {
+        self.span
+    }
+
+    /// Where each step of the last [`bin`](Self::bin) result came from, in
+    /// the same order.
+    #[must_use]
+    pub fn sources(&self) -> Vec<Source> {
+        let off = self.clip_start;
+        let shift = |b: &Bucket| Source {
+            start: b.start + off,
+            end: b.end + off,
+            min_index: b.min_index + off,
+            max_index: b.max_index + off,
+        };
+        if self.buckets.is_empty() {
+            // too few samples to bin: passed through one by one
+            return (off..off + self.last_len).map(Source::single).collect();
+        }
+        match self.strat {
+            Strategy::Lttb => std::iter::once(Source::single(off))
+                .chain(self.buckets.iter().map(shift))
+                .chain(std::iter::once(Source::single(off + self.last_len - 1)))
+                .collect(),
+            _ => self.buckets.iter().map(shift).collect(),
+        }
+    }
+
+    /// `breaks[i]`: a gap of more than `threshold`× the median sample
+    /// spacing lies between the samples behind step `i` of the last
+    /// [`bin`](Self::bin) of `data` and those of the step drawn before it,
+    /// or among them.  Found on `data`, as the binned steps are always about
+    /// one bucket apart.
+    #[must_use]
+    pub fn breaks(&self, data: &[DataTimeStep], threshold: f64) -> Vec<bool> {
+        let sources = self.sources();
+        let lo = sources.iter().map(|s| s.start).min().unwrap_or(0);
+        let hi = sources.iter().map(|s| s.end).max().unwrap_or(0);
+        // gaps[j - lo]: a gap between samples j - 1 and j
+        let gaps = gaps(&data[lo..hi], Some(threshold));
+        let mut next = None; // first sample after the previous drawn step
+        sources
+            .iter()
+            .map(|s| {
+                if s.start == s.end {
+                    return false;
+                }
+                let from = next.unwrap_or(s.start + 1);
+                next = Some(s.end);
+                (from..s.end).any(|j| gaps[j - lo])
+            })
+            .collect()
+    }
+
+    /// Bin the samples inside `config.x_range` (all of them without one).
+    /// `data` must be sorted by time.
     pub fn bin(&mut self, data: &[DataTimeStep], config: &Config) -> Vec<DataTimeStep> {
         // Determine current target bin count
-        let target = config.x_chars * HR;
+        let target = config.dot_columns();
+
+        // Growing history: fold in the new samples only.
+        let (data, clip_start) = clip(data, config.x_range);
+        self.clip_start = clip_start;
+        self.span = match (self.strat, config.x_range) {
+            (Strategy::Time, Some(range)) => Some(range),
+            _ => data.first().zip(data.last()).map(|(a, b)| (a.time, b.time)),
+        };
+        if self.cached
+            && self.target == target
+            && config.x_range == self.last_xrange
+            && (config.x_range.is_none() || self.strat == Strategy::Index)
+            && self.strat != Strategy::Lttb
+            && !matches!(self.agg, Aggregate::Percentile { .. })
+            && data.len() > target
+            && self.appended(data)
+            && let Some(out) = self.bin_append(data)
+        {
+            return out;
+        }
+        if !self.fine.is_empty() {
+            // buckets were combined from fine ones: start over
+            self.fine.clear();
+            self.cached = false;
+        }
 
         // Cache invalidation triggers
         let xrange_changed = config.x_range != self.last_xrange;
-        if self.strat != Strategy::Index // strategy switch
+        if self.strat == Strategy::Time // time buckets are rebuilt every call
             || self.target != target     // terminal resize
             || xrange_changed
         // new clip window
@@ -438,6 +991,231 @@ impl Binner {
         match self.strat {
             Strategy::Index => self.bin_index(data),
             Strategy::Time => self.bin_time(data, config),
+            Strategy::Lttb => self.bin_lttb(data),
+        }
+    }
+}
+
+/// Union of two adjacent buckets (either may be empty).
+fn merge(a: Bucket, b: &Bucket) -> Bucket {
+    if b.end == b.start {
+        return a;
+    }
+    if a.end == a.start {
+        return b.clone();
+    }
+    let (min, min_index) = if b.min < a.min {
+        (b.min, b.min_index)
+    } else {
+        (a.min, a.min_index)
+    };
+    let (max, max_index) = if b.max > a.max {
+        (b.max, b.max_index)
+    } else {
+        (a.max, a.max_index)
+    };
+    Bucket {
+        start: a.start,
+        end: b.end,
+        min,
+        max,
+        min_index,
+        max_index,
+        sum: a.sum + b.sum,
+        sum_sq: a.sum_sq + b.sum_sq,
+        band: a.band,
+    }
+}
+
+/// The samples with `lo <= time <= hi` (binary search on sorted times),
+/// and the index of the first one.
+fn clip(data: &[DataTimeStep], range: Option<(f64, f64)>) -> (&[DataTimeStep], usize) {
+    let Some((lo, hi)) = range else {
+        return (data, 0);
+    };
+    let start = data.partition_point(|p| p.time < lo);
+    let end = data.partition_point(|p| p.time <= hi).max(start);
+    (&data[start..end], start)
+}
+
+/// Nearest-rank `p`-th percentile (0–100); reorders `v`.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)] // rank within 0..len
+fn percentile(v: &mut [f64], p: f64) -> f64 {
+    let rank = (p / 100.0 * (v.len() - 1) as f64).round() as usize;
+    *v.select_nth_unstable_by(rank.min(v.len() - 1), f64::total_cmp)
+        .1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(x_chars: usize) -> Config {
+        Config::builder(x_chars, 10)
+            .y_range(0.0..=10.0)
+            .build()
+            .unwrap()
+    }
+
+    fn samples(times: impl IntoIterator<Item = f64>) -> Vec<DataTimeStep> {
+        times
+            .into_iter()
+            .map(|t| DataTimeStep {
+                time: t,
+                min: t,
+                max: t,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn time_last_bucket_holds_newest_sample() {
+        for x_chars in 1..=200 {
+            let data = samples([1.0, 2.0, 3.0]);
+            let mut binner = Binner::new(Strategy::Time);
+            let out = binner.bin(&data, &config(x_chars));
+            assert!(out.last().unwrap().max >= 3.0, "{x_chars} columns");
+            let last = binner.sources().last().copied().unwrap();
+            assert_eq!(last.end, data.len(), "{x_chars} columns");
+        }
+    }
+
+    #[test]
+    fn breaks_find_gap_narrower_than_a_bucket() {
+        // spacing 1 with one hole of 5, steps ~10 per bucket
+        let data = samples((0..100).map(|i| f64::from(i) + if i < 50 { 0.0 } else { 4.0 }));
+        let mut binner = Binner::new(Strategy::Time);
+        let steps = binner.bin(&data, &config(5));
+        assert!(!gaps(&steps, Some(3.0)).contains(&true));
+        let breaks = binner.breaks(&data, 3.0);
+        assert_eq!(breaks.len(), steps.len());
+        assert_eq!(breaks.iter().filter(|b| **b).count(), 1);
+    }
+
+    #[test]
+    fn breaks_ignore_blank_buckets_of_a_dense_series() {
+        // slightly sparser than the buckets: every few buckets stay blank
+        let data = samples((0..50).map(|i| f64::from(i) * 1.2));
+        let mut binner = Binner::new(Strategy::Time);
+        let steps = binner.bin(&data, &config(30));
+        let drawn: Vec<_> = steps
+            .iter()
+            .filter(|p| p.min.is_finite())
+            .copied()
+            .collect();
+        assert!(gaps(&drawn, Some(1.5)).contains(&true));
+        assert!(!binner.breaks(&data, 1.5).contains(&true));
+    }
+
+    #[test]
+    fn lttb_scroll_matches_full_rebuild() {
+        let data: Vec<_> = (0..2000)
+            .map(|i| {
+                let v = f64::from((i * 7919) % 1000);
+                DataTimeStep {
+                    time: f64::from(i),
+                    min: v,
+                    max: v,
+                }
+            })
+            .collect();
+        // a fixed window scrolling, a growing one (bucket width changes on
+        // every call), a resize, and a window that shrinks back
+        let frames = (0..600)
+            .map(|start| (start, start + 500, 40))
+            .chain((0..400).map(|grow| (600, 1100 + grow, 40)))
+            .chain((0..200).map(|start| (600 + start, 1500 + start, 25)))
+            .chain((0..100).map(|shrink| (800, 1700 - 3 * shrink, 25)));
+
+        let mut scrolling = Binner::new(Strategy::Lttb);
+        for (lo, hi, x_chars) in frames {
+            let cfg = config(x_chars);
+            let window = &data[lo..hi];
+            let got = scrolling.bin(window, &cfg);
+            let mut fresh = Binner::new(Strategy::Lttb);
+            let want = fresh.bin(window, &cfg);
+            let times = |v: &[DataTimeStep]| v.iter().map(|p| p.time.to_bits()).collect::<Vec<_>>();
+            assert_eq!(times(&got), times(&want), "window {lo}..{hi}");
+            assert_eq!(scrolling.sources(), fresh.sources(), "window {lo}..{hi}");
+        }
+    }
+
+    #[test]
+    fn appending_one_by_one_matches_full_rebuild() {
+        let cfg = config(5);
+        let target = cfg.dot_columns();
+        let data: Vec<_> = (0..1300u32)
+            .map(|i| {
+                let v = f64::from(i * 37 % 101);
+                DataTimeStep {
+                    time: f64::from(i),
+                    min: v,
+                    max: v + 1.0,
+                }
+            })
+            .collect();
+
+        let mut appending = Binner::new(Strategy::Index);
+        appending.bin(&data[..=target], &cfg);
+        for n in target + 2..=data.len() {
+            let steps = appending.bin(&data[..n], &cfg);
+            assert!(
+                !appending.fine.is_empty(),
+                "{n} samples: append path not taken"
+            );
+
+            // every bucket is the exact envelope of the samples it covers
+            let sources = appending.sources();
+            assert_eq!(sources.first().unwrap().start, 0);
+            assert_eq!(sources.last().unwrap().end, n);
+            for (w, (src, step)) in sources.windows(2).zip(sources.iter().zip(&steps)) {
+                assert_eq!(w[0].end, w[1].start, "{n} samples");
+                let own = &data[src.start..src.end];
+                let lo = own.iter().map(|p| p.min).fold(f64::INFINITY, f64::min);
+                let hi = own.iter().map(|p| p.max).fold(f64::NEG_INFINITY, f64::max);
+                assert_eq!(
+                    (step.min.to_bits(), step.max.to_bits()),
+                    (lo.to_bits(), hi.to_bits())
+                );
+            }
+
+            // where fine buckets line up with the output ones (before and
+            // after each halving), the buckets are the full rebuild's
+            if n % (FINE_PER_BIN * target) == 0 && (n / (FINE_PER_BIN * target)).is_power_of_two() {
+                let mut fresh = Binner::new(Strategy::Index);
+                let full = fresh.bin(&data[..n], &cfg);
+                assert_eq!(appending.sources(), fresh.sources(), "{n} samples");
+                let bits = |v: &[DataTimeStep]| -> Vec<_> {
+                    v.iter()
+                        .map(|p| (p.time.to_bits(), p.min.to_bits(), p.max.to_bits()))
+                        .collect()
+                };
+                assert_eq!(bits(&steps), bits(&full), "{n} samples");
+            }
         }
     }
+
+    #[test]
+    fn clip_keeps_the_samples_inside_the_range() {
+        let data = samples((0..10).map(f64::from));
+        let clipped = |lo: f64, hi: f64| {
+            let (v, start) = clip(&data, Some((lo, hi)));
+            (v.iter().map(|p| p.time).collect::<Vec<_>>(), start)
+        };
+        assert_eq!(clip(&data, None).0.len(), 10);
+        // both ends included
+        assert_eq!(clipped(2.0, 5.0), (vec![2.0, 3.0, 4.0, 5.0], 2));
+        assert_eq!(clipped(0.0, 9.0).0.len(), 10);
+        assert_eq!(clipped(2.5, 3.5), (vec![3.0], 3));
+        assert_eq!(clipped(9.0, 20.0), (vec![9.0], 9));
+        // fully outside, on either side
+        assert_eq!(clipped(20.0, 30.0), (vec![], 10));
+        assert_eq!(clipped(-5.0, -1.0), (vec![], 0));
+        // reversed: empty, never a panic
+        assert_eq!(clipped(5.0, 2.0), (vec![], 5));
+    }
 }
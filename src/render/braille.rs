@@ -1,7 +1,10 @@
 //! Turns raw data into character and subcharacter positioning with braille.
 
 use crate::core::{
-    config::Config, constants::BRAILLE_VERTICAL_RESOLUTION, data::DataTimeStep, error::GraphError,
+    color::{AnsiCode, DENSITY_RAMP},
+    config::{Baseline, Config, PlotStyle},
+    data::{DataTimeStep, Series},
+    error::GraphError,
 };
 
 /// After `filter_and_bin` the *logical* per-timestep min/max are mapped to these
@@ -12,15 +15,107 @@ pub struct GraphTimeStep {
     pub max: usize,
 }
 
+/// One series worth of pixel spans, drawn in a single color.  `None` is a
+/// blank dot column (no data).
+pub struct Layer {
+    pub name: String,
+    pub color: AnsiCode,
+    pub steps: Vec<Option<GraphTimeStep>>,
+    /// Free-standing dots instead of spans ([`PlotStyle::Scatter`]).
+    pub scatter: Option<Scatter>,
+}
+
+/// Dots on a 2D grid: one dot mask per character cell, row-major.
+pub struct Scatter {
+    /// Cells per row (`Config::x_chars`).
+    pub width: usize,
+    /// Dots per row (`Config::dot_columns`).
+    pub dot_columns: usize,
+    /// Cell masks, dot `(x, y)` at bit `y * cols + x` (see
+    /// [`Glyphs::encode`](crate::core::glyphs::Glyphs::encode)).
+    pub masks: Vec<u8>,
+    /// Points per cell, when colored by density (empty otherwise).
+    pub counts: Vec<u32>,
+    pub peak: u32,
+}
+
+impl Scatter {
+    /// Dot mask and, when colored by density, color of cell (`col`, `row`).
+    #[inline]
+    #[must_use]
+    pub fn cell(&self, col: usize, row: usize) -> (u8, Option<AnsiCode>) {
+        let i = row * self.width + col;
+        let mask = self.masks.get(i).copied().unwrap_or(0);
+        let color = match self.counts.get(i) {
+            Some(&n) if n > 0 => {
+                let level = (n as usize * DENSITY_RAMP.len()).div_ceil(self.peak as usize);
+                Some(DENSITY_RAMP[level.clamp(1, DENSITY_RAMP.len()) - 1])
+            }
+            _ => None,
+        };
+        (mask, color)
+    }
+}
+
+/// Layers are painted in order; see [`Overlap`](crate::core::config::Overlap)
+/// for how shared cells are colored.
 pub struct BraillePlot {
-    pub steps: Vec<GraphTimeStep>,
+    pub layers: Vec<Layer>,
+    /// Time range under the plot, for x-axis labels: the first and last
+    /// plotted step unless set otherwise.  Line and scatter plots put its
+    /// ends on the first and last dot column; otherwise every dot column
+    /// covers an equal share of it (e.g. [`Binner::span`](crate::Binner::span)).
+    pub x_span: Option<(f64, f64)>,
+}
+
+impl BraillePlot {
+    /// Draw `other`'s layers on top of this plot.
+    pub fn overlay(&mut self, other: BraillePlot) {
+        self.layers.extend(other.layers);
+        self.x_span = merge_span(self.x_span, other.x_span);
+    }
+
+    /// Width of the widest layer in dot columns.
+    #[must_use]
+    pub fn dot_columns(&self) -> usize {
+        self.layers
+            .iter()
+            .map(|l| match &l.scatter {
+                Some(s) => s.dot_columns,
+                None => l.steps.len(),
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn span_of(v: &[DataTimeStep]) -> Option<(f64, f64)> {
+    Some((v.first()?.time, v.last()?.time))
+}
+
+fn merge_span(a: Option<(f64, f64)>, b: Option<(f64, f64)>) -> Option<(f64, f64)> {
+    match (a, b) {
+        (Some((a0, a1)), Some((b0, b1))) => Some((a0.min(b0), a1.max(b1))),
+        (a, None) => a,
+        (None, b) => b,
+    }
 }
 
-/// Convert `DataTimeStep` list into pixel coordinates (+ optional bridging).
+/// With [`PlotStyle::Line`] and [`PlotStyle::Scatter`] every layer spans the
+/// x range (or the data) at one dot column per step, so layers line up and
+/// the labels stay correct.
+fn line_span(config: &Config, span: Option<(f64, f64)>) -> Option<(f64, f64)> {
+    match config.style {
+        PlotStyle::Line | PlotStyle::Scatter { .. } => config.x_range.or(span),
+        _ => span,
+    }
+}
+
+/// Convert `DataTimeStep` list into a single-layer plot in `config.color`.
 ///
-/// Safety-critical invariants:
-/// * `GraphTimeStep::min  <= GraphTimeStep::max`
-/// * both are in `[0 , vert_px-1]` inclusive
+/// # Errors
+///
+/// [`GraphError::EmptyData`] when `v` is empty.
 pub fn preprocess_to_braille(
     v: &[DataTimeStep],
     config: &Config,
@@ -29,52 +124,583 @@ pub fn preprocess_to_braille(
     if v.is_empty() {
         return Err(GraphError::EmptyData);
     }
+    let x_span = line_span(config, span_of(v));
+    let mut layers = Vec::with_capacity(1);
+    push_layers(
+        &mut layers,
+        "",
+        config.color,
+        v,
+        &[],
+        config,
+        bridge,
+        x_span,
+    );
+    Ok(BraillePlot { layers, x_span })
+}
+
+/// Convert several (already binned) series into one layer each, colored by
+/// [`Config::series_color`].  Empty series yield empty layers.
+///
+/// # Errors
+///
+/// [`GraphError::EmptyData`] when every series is empty.
+pub fn preprocess_series(
+    series: &[Series],
+    config: &Config,
+    bridge: bool,
+) -> Result<BraillePlot, GraphError> {
+    if series.iter().all(|s| s.steps.is_empty()) {
+        return Err(GraphError::EmptyData);
+    }
+    let span = series
+        .iter()
+        .map(|s| span_of(&s.steps))
+        .fold(None, merge_span);
+    let x_span = line_span(config, span);
+    let mut layers = Vec::with_capacity(series.len());
+    for (i, s) in series.iter().enumerate() {
+        let color = config.series_color(i);
+        push_layers(
+            &mut layers,
+            &s.name,
+            color,
+            &s.steps,
+            &s.breaks,
+            config,
+            bridge,
+            x_span,
+        );
+    }
+    Ok(BraillePlot { layers, x_span })
+}
 
-    let vert_px = config.y_chars * BRAILLE_VERTICAL_RESOLUTION;
-    let y_span = config.y_max - config.y_min; // cfg validated: > 0
+/// One layer for the series, plus an unnamed one for the area below the
+/// baseline when that has its own [`Config::below_color`].
+#[allow(clippy::too_many_arguments)] // the series' parts plus how to draw it
+fn push_layers(
+    out: &mut Vec<Layer>,
+    name: &str,
+    color: AnsiCode,
+    v: &[DataTimeStep],
+    breaks: &[bool],
+    config: &Config,
+    bridge: bool,
+    x_span: Option<(f64, f64)>,
+) {
+    let (steps, scatter) = match (config.style, x_span) {
+        (PlotStyle::Area(baseline), _) => {
+            let (above, below) = area(v, config, baseline, config.below_color.is_some());
+            if let Some(c) = config.below_color {
+                out.push(Layer {
+                    name: name.to_owned(),
+                    color,
+                    steps: above,
+                    scatter: None,
+                });
+                out.push(Layer {
+                    name: String::new(),
+                    color: c,
+                    steps: below,
+                    scatter: None,
+                });
+                return;
+            }
+            (above, None)
+        }
+        (PlotStyle::Scatter { density }, Some(span)) => {
+            (Vec::new(), Some(scatter(v, config, span, density)))
+        }
+        _ => (rasterize(v, breaks, config, bridge, x_span), None),
+    };
+    out.push(Layer {
+        name: name.to_owned(),
+        color,
+        steps,
+        scatter,
+    });
+}
 
-    // Robust mapping λ(y): ℝ → [0 , vert_px-1]
-    let inv = |y: f64| -> usize {
-        // Normalise into [0,1]  (values slightly outside due to float error
-        // or user-supplied y_min/y_max are gracefully clamped).
-        let ratio = ((y - config.y_min) / y_span).max(0.0).min(1.0);
+/// Robust mapping λ(y): ℝ → [0 , vert_px-1], with `y_max` on pixel row 0.
+#[inline]
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)] // ratio clamped to [0, 1] of the dot rows
+pub(crate) fn y_to_px(y: f64, config: &Config) -> usize {
+    let vert_px = config.dot_rows();
+    let scale = config.y_scale;
+    let lo = scale.apply(config.y_min);
+    let y_span = scale.apply(config.y_max) - lo; // cfg validated: > 0
 
-        // Scale to pixel grid, round to nearest integer, then invert so
-        // logical “top” (y_max) maps to row 0.
-        let r = (ratio * (vert_px - 1) as f64).round() as usize;
-        (vert_px - 1) - r
+    // Normalise into [0,1]  (values slightly outside due to float error
+    // or user-supplied y_min/y_max are gracefully clamped; values a log
+    // scale can't show sit on the bottom row).
+    let ratio = (scale.apply(y) - lo) / y_span;
+    let ratio = if ratio.is_nan() {
+        0.0
+    } else {
+        ratio.clamp(0.0, 1.0)
     };
 
+    // Scale to pixel grid, round to nearest integer, then invert so
+    // logical “top” (y_max) maps to row 0.
+    let r = (ratio * (vert_px - 1) as f64).round() as usize;
+    (vert_px - 1) - r
+}
+
+/// Map `DataTimeStep`s to pixel coordinates (+ optional bridging).  Steps
+/// with a non-finite `min` or `max` become blank columns.  `breaks` as in
+/// [`Series::breaks`].
+///
+/// Safety-critical invariants:
+/// * `GraphTimeStep::min  <= GraphTimeStep::max`
+/// * both are in `[0 , vert_px-1]` inclusive
+fn rasterize(
+    v: &[DataTimeStep],
+    breaks: &[bool],
+    config: &Config,
+    bridge: bool,
+    x_span: Option<(f64, f64)>,
+) -> Vec<Option<GraphTimeStep>> {
+    if v.is_empty() {
+        return Vec::new();
+    }
+    if config.style == PlotStyle::Line
+        && let Some(span) = x_span
+    {
+        return polyline(v, breaks, config, span);
+    }
+
+    let inv = |y: f64| y_to_px(y, config);
+
     // Initial point-wise mapping
-    let mut steps: Vec<GraphTimeStep> = v
+    let mut steps: Vec<Option<GraphTimeStep>> = v
         .iter()
         .map(|p| {
+            if !p.min.is_finite() || !p.max.is_finite() {
+                return None;
+            }
             let mut low = inv(p.min);
             let mut high = inv(p.max);
             if low > high {
                 std::mem::swap(&mut low, &mut high);
             }
-            GraphTimeStep {
+            Some(GraphTimeStep {
                 min: low,
                 max: high,
-            }
+            })
         })
         .collect();
 
-    // Optional min/max “bridging” pass
+    // Optional min/max “bridging” pass (never across a blank column or gap)
     if bridge {
+        let gaps = if breaks.is_empty() {
+            gaps(v, config.gap_threshold)
+        } else {
+            breaks.to_vec()
+        };
         let mut bridged = Vec::with_capacity(steps.len());
         bridged.push(steps[0].clone()); // first point unchanged
         for i in 1..steps.len() {
-            let prev = &steps[i - 1];
-            let curr = &steps[i];
-            bridged.push(GraphTimeStep {
-                min: prev.min.min(curr.min + 1), // +1 so lines touch
-                max: prev.max.max(curr.max.saturating_sub(1)),
+            bridged.push(match (&steps[i - 1], &steps[i]) {
+                (Some(prev), Some(curr)) if !gaps[i] => Some(GraphTimeStep {
+                    min: prev.min.min(curr.min + 1), // +1 so lines touch
+                    max: prev.max.max(curr.max.saturating_sub(1)),
+                }),
+                (_, curr) => curr.clone(),
             });
         }
         steps = bridged;
     }
 
-    Ok(BraillePlot { steps })
+    steps
+}
+
+/// Fill each step out from the baseline: dots from the baseline row up to
+/// `max` (if above) and down to `min` (if below).  With `split` the part
+/// below the baseline goes into the second vector instead of the first.
+fn area(
+    v: &[DataTimeStep],
+    config: &Config,
+    baseline: Baseline,
+    split: bool,
+) -> (Vec<Option<GraphTimeStep>>, Vec<Option<GraphTimeStep>>) {
+    let base = baseline.value(config);
+    let base_px = y_to_px(base, config);
+    let mut above = Vec::with_capacity(v.len());
+    let mut below = Vec::with_capacity(if split { v.len() } else { 0 });
+
+    for p in v {
+        if !p.min.is_finite() || !p.max.is_finite() {
+            above.push(None);
+            if split {
+                below.push(None);
+            }
+            continue;
+        }
+        let top = y_to_px(p.max.max(p.min), config);
+        let bottom = y_to_px(p.min.min(p.max), config);
+        let up = (p.max >= base).then_some(GraphTimeStep {
+            min: top.min(base_px),
+            max: base_px,
+        });
+        // below the baseline row, or from it when nothing is above
+        let from = if up.is_some() { base_px + 1 } else { base_px };
+        let down = (p.min < base && bottom >= from).then_some(GraphTimeStep {
+            min: from,
+            max: bottom,
+        });
+
+        if split {
+            above.push(up);
+            below.push(down);
+        } else {
+            above.push(match (up, down) {
+                (Some(u), Some(d)) => Some(GraphTimeStep {
+                    min: u.min,
+                    max: d.max,
+                }),
+                (u, d) => u.or(d),
+            });
+        }
+    }
+    (above, below)
+}
+
+/// Light one dot per point (both ends of a min/max pair) on the plot grid;
+/// points outside `lo..=hi` or the y range are left out.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)] // position clamped into the span of dot columns
+fn scatter(v: &[DataTimeStep], config: &Config, (lo, hi): (f64, f64), density: bool) -> Scatter {
+    let width = config.x_chars;
+    let cells = width * config.y_chars;
+    let cols = config.dot_columns();
+    let (cell_w, cell_h) = (config.glyphs.cols(), config.glyphs.rows());
+    let mut grid = Scatter {
+        width,
+        dot_columns: cols,
+        masks: vec![0; cells],
+        counts: if density { vec![0; cells] } else { Vec::new() },
+        peak: 0,
+    };
+    if cols == 0 {
+        return grid;
+    }
+
+    let in_y = |y: f64| (config.y_min..=config.y_max).contains(&y);
+    for p in v.iter().filter(|p| (lo..=hi).contains(&p.time)) {
+        let x = if hi > lo {
+            ((p.time - lo) / (hi - lo) * (cols - 1) as f64).round() as usize
+        } else {
+            0
+        };
+        let lo_px = in_y(p.min).then(|| y_to_px(p.min, config));
+        let hi_px = in_y(p.max)
+            .then(|| y_to_px(p.max, config))
+            .filter(|&y| Some(y) != lo_px);
+        for dot_y in lo_px.into_iter().chain(hi_px) {
+            let cell = dot_y / cell_h * width + x / cell_w;
+            grid.masks[cell] |= 1 << (dot_y % cell_h * cell_w + x % cell_w);
+            if density {
+                grid.counts[cell] += 1;
+                grid.peak = grid.peak.max(grid.counts[cell]);
+            }
+        }
+    }
+    grid
+}
+
+/// One step per dot column across `lo..=hi`: each point's min..max span at
+/// the column its time maps to, joined to the previous point by a straight
+/// line through their midpoints.  Blank steps are skipped over; only a
+/// [`Config::gap_threshold`] gap breaks the line.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)] // position clamped into the span of dot columns
+fn polyline(
+    steps_in: &[DataTimeStep],
+    breaks: &[bool],
+    config: &Config,
+    (lo, hi): (f64, f64),
+) -> Vec<Option<GraphTimeStep>> {
+    let cols = config.dot_columns();
+    let mut steps: Vec<Option<GraphTimeStep>> = vec![None; cols];
+    if cols == 0 {
+        return steps;
+    }
+    let col = |t: f64| {
+        if hi > lo {
+            ((t - lo) / (hi - lo) * (cols - 1) as f64).round() as usize
+        } else {
+            0
+        }
+    };
+    let mut light = |x: usize, y0: usize, y1: usize| {
+        let s = steps[x].get_or_insert(GraphTimeStep { min: y0, max: y1 });
+        s.min = s.min.min(y0);
+        s.max = s.max.max(y1);
+    };
+
+    // a break before a skipped step carries over to the next point drawn
+    let mut points = Vec::with_capacity(steps_in.len());
+    let mut carried = Vec::with_capacity(if breaks.is_empty() { 0 } else { steps_in.len() });
+    let mut pending = false;
+    for (i, p) in steps_in.iter().enumerate() {
+        pending |= breaks.get(i).copied().unwrap_or(false);
+        if p.min.is_finite() && p.max.is_finite() && (lo..=hi).contains(&p.time) {
+            points.push(*p);
+            if !breaks.is_empty() {
+                carried.push(pending);
+            }
+            pending = false;
+        }
+    }
+    let gaps = if breaks.is_empty() {
+        gaps(&points, config.gap_threshold)
+    } else {
+        carried
+    };
+
+    let mut prev: Option<(usize, usize)> = None;
+    for (p, gap) in points.iter().zip(gaps) {
+        let x = col(p.time);
+        let (hi_px, lo_px) = (y_to_px(p.max, config), y_to_px(p.min, config));
+        let (top, bottom) = (hi_px.min(lo_px), hi_px.max(lo_px));
+        light(x, top, bottom);
+
+        let mid = usize::midpoint(top, bottom);
+        if let Some(from) = prev
+            && !gap
+        {
+            bresenham(from, (x, mid), |x, y| light(x, y, y));
+        }
+        prev = Some((x, mid));
+    }
+    steps
+}
+
+/// Visit every dot on the line from `from` to `to` (both included).
+#[allow(clippy::cast_possible_wrap)] // dot coordinates are far below isize::MAX
+fn bresenham(from: (usize, usize), to: (usize, usize), mut dot: impl FnMut(usize, usize)) {
+    let (mut x, mut y) = (from.0 as isize, from.1 as isize);
+    let (x1, y1) = (to.0 as isize, to.1 as isize);
+    let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+    let (sx, sy) = ((x1 - x).signum(), (y1 - y).signum());
+    let mut err = dx + dy;
+    loop {
+        // the line stays between its two non-negative endpoints
+        dot(x.unsigned_abs(), y.unsigned_abs());
+        if x == x1 && y == y1 {
+            return;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
+
+/// `gaps[i]`: step `i` is more than `threshold`× the median spacing after
+/// step `i - 1`.
+pub(crate) fn gaps(v: &[DataTimeStep], threshold: Option<f64>) -> Vec<bool> {
+    let mut gaps = vec![false; v.len()];
+    let Some(threshold) = threshold else {
+        return gaps;
+    };
+    let mut dt: Vec<f64> = v.windows(2).map(|w| w[1].time - w[0].time).collect();
+    if dt.is_empty() {
+        return gaps;
+    }
+    let mid = dt.len() / 2;
+    let median = *dt.select_nth_unstable_by(mid, f64::total_cmp).1;
+    if median > 0.0 {
+        for (i, w) in v.windows(2).enumerate() {
+            gaps[i + 1] = w[1].time - w[0].time > threshold * median;
+        }
+    }
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 5×2 cells: dot columns 0..10 for times 0..=9, and with `y_range`
+    /// eight units tall, one dot row per unit.
+    fn config(style: PlotStyle, y_range: std::ops::RangeInclusive<f64>) -> Config {
+        Config::builder(5, 2)
+            .y_range(y_range)
+            .x_range(0.0, 9.0)
+            .style(style)
+            .build()
+            .unwrap()
+    }
+
+    fn points(values: &[(f64, f64)]) -> Vec<DataTimeStep> {
+        values
+            .iter()
+            .map(|&(time, v)| DataTimeStep {
+                time,
+                min: v,
+                max: v,
+            })
+            .collect()
+    }
+
+    fn spans(steps: &[Option<GraphTimeStep>]) -> Vec<Option<(usize, usize)>> {
+        steps
+            .iter()
+            .map(|s| s.as_ref().map(|s| (s.min, s.max)))
+            .collect()
+    }
+
+    fn line(from: (usize, usize), to: (usize, usize)) -> Vec<(usize, usize)> {
+        let mut dots = Vec::new();
+        bresenham(from, to, |x, y| dots.push((x, y)));
+        dots
+    }
+
+    #[test]
+    fn bresenham_steep_segment_has_one_dot_per_row() {
+        let dots = line((0, 0), (2, 7));
+        assert_eq!(dots.first(), Some(&(0, 0)));
+        assert_eq!(dots.last(), Some(&(2, 7)));
+        assert_eq!(
+            dots.iter().map(|d| d.1).collect::<Vec<_>>(),
+            (0..=7).collect::<Vec<_>>()
+        );
+        assert!(dots.windows(2).all(|w| w[1].0 - w[0].0 <= 1));
+
+        assert_eq!(line((2, 7), (0, 0)).len(), dots.len());
+    }
+
+    #[test]
+    fn bresenham_shallow_segment_has_one_dot_per_column() {
+        let dots = line((0, 2), (7, 0));
+        assert_eq!(dots.first(), Some(&(0, 2)));
+        assert_eq!(dots.last(), Some(&(7, 0)));
+        assert_eq!(
+            dots.iter().map(|d| d.0).collect::<Vec<_>>(),
+            (0..=7).collect::<Vec<_>>()
+        );
+        assert!(dots.windows(2).all(|w| w[0].1.abs_diff(w[1].1) <= 1));
+        assert_eq!(line((3, 4), (3, 4)), [(3, 4)]);
+    }
+
+    #[test]
+    fn line_joins_points_and_breaks_at_a_gap() {
+        // y 0..=7 maps to dot rows 7..=0
+        let v = points(&[
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (7.0, 7.0),
+            (8.0, 7.0),
+            (9.0, 7.0),
+        ]);
+        let cfg = config(PlotStyle::Line, 0.0..=7.0);
+        let joined = rasterize(&v, &[], &cfg, false, cfg.x_range);
+        assert!(joined.iter().all(Option::is_some));
+        assert_eq!(spans(&joined)[0], Some((7, 7)));
+        assert_eq!(spans(&joined)[9], Some((0, 0)));
+
+        // the 5 s jump is more than twice the median 1 s spacing
+        let cfg = Config::builder(5, 2)
+            .y_range(0.0..=7.0)
+            .x_range(0.0, 9.0)
+            .style(PlotStyle::Line)
+            .gap_threshold(2.0)
+            .build()
+            .unwrap();
+        assert_eq!(
+            gaps(&v, cfg.gap_threshold),
+            [false, false, false, true, false, false]
+        );
+        let broken = spans(&rasterize(&v, &[], &cfg, false, cfg.x_range));
+        assert_eq!(&broken[..3], [Some((7, 7)); 3]);
+        assert_eq!(&broken[3..7], [None; 4]);
+        assert_eq!(&broken[7..], [Some((0, 0)); 3]);
+
+        // breaks given by the series win over the threshold
+        let breaks = [false, false, false, false, true, false];
+        let moved = spans(&rasterize(&v, &breaks, &cfg, false, cfg.x_range));
+        assert!(moved[3..7].iter().all(Option::is_some));
+        assert_eq!(moved[7], Some((0, 0)));
+    }
+
+    #[test]
+    fn area_fills_to_the_baseline() {
+        // y -4..=3: value y on dot row 3 - y, zero on row 3
+        let v = points(&[(0.0, 2.0), (1.0, -3.0)]);
+        let fill = |baseline| {
+            let cfg = config(PlotStyle::Area(baseline), -4.0..=3.0);
+            let plot = preprocess_to_braille(&v, &cfg, false).unwrap();
+            assert_eq!(plot.layers.len(), 1);
+            spans(&plot.layers[0].steps)
+        };
+        assert_eq!(fill(Baseline::Zero), [Some((1, 3)), Some((3, 6))]);
+        assert_eq!(fill(Baseline::YMin), [Some((1, 7)), Some((6, 7))]);
+        assert_eq!(fill(Baseline::Value(1.0)), [Some((1, 2)), Some((2, 6))]);
+    }
+
+    #[test]
+    fn area_below_the_baseline_gets_its_own_color() {
+        let v = points(&[(0.0, 2.0), (1.0, -3.0)]);
+        let cfg = Config::builder(5, 2)
+            .y_range(-4.0..=3.0)
+            .style(PlotStyle::Area(Baseline::Zero))
+            .color(AnsiCode::green())
+            .below_color(AnsiCode::red())
+            .build()
+            .unwrap();
+        let plot = preprocess_to_braille(&v, &cfg, false).unwrap();
+        let [above, below] = &plot.layers[..] else {
+            panic!("expected an above and a below layer");
+        };
+        assert_eq!(above.color, AnsiCode::green());
+        assert_eq!(spans(&above.steps), [Some((1, 3)), None]);
+        assert_eq!(below.color, AnsiCode::red());
+        assert!(below.name.is_empty());
+        assert_eq!(spans(&below.steps), [None, Some((3, 6))]);
+    }
+
+    #[test]
+    fn scatter_counts_points_per_cell() {
+        // two points on one dot and one beside it in the top-left cell, one
+        // in the bottom-right cell, and one above the y range
+        let v = points(&[(0.0, 7.0), (0.0, 7.0), (1.0, 7.0), (9.0, 0.0), (5.0, 9.0)]);
+        let cfg = config(PlotStyle::Scatter { density: true }, 0.0..=7.0);
+        let plot = preprocess_to_braille(&v, &cfg, false).unwrap();
+        let grid = plot.layers[0].scatter.as_ref().unwrap();
+
+        assert_eq!(grid.counts.iter().sum::<u32>(), 4);
+        assert_eq!(grid.counts[0], 3);
+        assert_eq!(grid.counts[9], 1);
+        assert_eq!(grid.peak, 3);
+        // dot (x, y) of a cell is bit y * 2 + x
+        assert_eq!(grid.masks[0], 0b11);
+        assert_eq!(grid.masks[9], 1 << 7);
+
+        assert_eq!(grid.cell(0, 0), (0b11, Some(DENSITY_RAMP[4])));
+        assert_eq!(grid.cell(4, 1), (1 << 7, Some(DENSITY_RAMP[1])));
+        assert_eq!(grid.cell(2, 0), (0, None));
+
+        let cfg = config(PlotStyle::Scatter { density: false }, 0.0..=7.0);
+        let plot = preprocess_to_braille(&v, &cfg, false).unwrap();
+        let grid = plot.layers[0].scatter.as_ref().unwrap();
+        assert!(grid.counts.is_empty());
+        assert_eq!(grid.cell(0, 0), (0b11, None));
+    }
 }
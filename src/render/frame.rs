@@ -1,38 +1,85 @@
-//! Build a full-screen braille frame and flush to the terminal.
+//! Build a full-screen braille frame and flush it to the terminal or any
+//! other `io::Write`.
 
-use std::io::{Write, stdout};
+use std::{
+    fmt::Write as _,
+    io::{Write, stdout},
+    ops::{Deref, DerefMut},
+};
 
 use crate::{
     core::{
+        bounds::{y_label, y_ticks},
         color::{AnsiCode, colorize},
-        config::Config,
+        config::{Config, Overlap, PlotStyle},
         constants::{
-            BORDER_WIDTH, DECIMAL_PRECISION, LABEL_GUTTER, MIN_GRAPH_HEIGHT, MIN_GRAPH_WIDTH,
+            BORDER_WIDTH, DECIMAL_PRECISION, FRAME_ROWS, LABEL_GUTTER, MIN_GRAPH_HEIGHT,
+            MIN_GRAPH_WIDTH,
         },
         error::GraphError,
+        glyphs::Glyphs,
+    },
+    render::{
+        braille::{BraillePlot, y_to_px},
+        layout::{Layout, Panel, compose},
     },
-    render::braille::{BraillePlot, GraphTimeStep},
 };
 
-// Public constants (re-exported by braille.rs)
-pub(crate) const BRAILLE_DOT_POSITIONS: [[u8; 4]; 2] = [
-    [0, 1, 2, 6], // left  column: dots 1,2,3,7
-    [3, 4, 5, 7], // right column: dots 4,5,6,8
-];
-pub(crate) const BRAILLE_UNICODE_BASE: u32 = 0x2800;
-
 // Layout constants
 
 /// Two spaces in front, one space behind
 const TITLE_PADDING: usize = 3;
 
+/// Width of the legend color sample ([`Glyphs::swatch`]) incl. the space
+/// separating it from the name.
+const LEGEND_SWATCH_WIDTH: usize = 2;
+
+/// Minimum blank columns between two x-axis labels.
+const X_LABEL_GAP: usize = 2;
+
+/// Dim grey for gridlines.
+const GRID_COLOR: AnsiCode = AnsiCode::Static("\x1b[38;2;80;80;80m");
+
 // Box-drawing glyphs
-const TL: &str = "┌";
-const TR: &str = "┐";
-const BL: &str = "└";
-const BR: &str = "┘";
-const H: &str = "─";
-const V: &str = "│";
+struct BoxChars {
+    tl: &'static str,
+    tr: &'static str,
+    bl: &'static str,
+    br: &'static str,
+    h: &'static str,
+    v: &'static str,
+    /// Tees joining a title bar to the frame above.
+    lt: &'static str,
+    rt: &'static str,
+}
+const UNICODE_BOX: BoxChars = BoxChars {
+    tl: "┌",
+    tr: "┐",
+    bl: "└",
+    br: "┘",
+    h: "─",
+    v: "│",
+    lt: "├",
+    rt: "┤",
+};
+const ASCII_BOX: BoxChars = BoxChars {
+    tl: "+",
+    tr: "+",
+    bl: "+",
+    br: "+",
+    h: "-",
+    v: "|",
+    lt: "+",
+    rt: "+",
+};
+
+#[inline]
+fn box_chars(cfg: &Config) -> &'static BoxChars {
+    match cfg.glyphs {
+        Glyphs::Ascii => &ASCII_BOX,
+        _ => &UNICODE_BOX,
+    }
+}
 
 // Utilities
 #[inline]
@@ -47,135 +94,398 @@ fn hash64(s: &str) -> u64 {
     h
 }
 
+/// `text` in `color`, or bare when the frame is plain.
+#[inline]
+fn paint(cfg: &Config, color: &AnsiCode, text: &str) -> String {
+    if cfg.colored {
+        colorize(color, text)
+    } else {
+        text.to_owned()
+    }
+}
+
 /// Write centred colored text between horizontal rules.
-fn push_centered(buf: &mut String, text: &str, width: usize, color: &AnsiCode) {
+fn push_centered(buf: &mut String, text: &str, width: usize, cfg: &Config) {
+    let h = box_chars(cfg).h;
     let inner = width.saturating_sub(TITLE_PADDING);
     let len = text.chars().count();
     if len == 0 || len > inner {
-        buf.push_str(&H.repeat(width));
+        buf.push_str(&h.repeat(width));
         return;
     }
     let pad_left = (inner - len) / 2;
     let pad_right = inner - len - pad_left;
 
-    buf.push_str(&H.repeat(pad_left));
+    buf.push_str(&h.repeat(pad_left));
     buf.push_str("  "); // 2-char left padding
-    buf.push_str(&colorize(color, text));
+    buf.push_str(&paint(cfg, &cfg.color, text));
     buf.push(' '); // 1-char right padding
-    buf.push_str(&H.repeat(pad_right));
+    buf.push_str(&h.repeat(pad_right));
 }
 
-/// Map two half-columns at (`char_idx`,`row`) to a single Unicode braille scalar.
+/// Subtitle centred in whatever space the legend leaves, legend right-aligned.
+///
+/// The legend lists every named layer when at least two are named.  Entries
+/// that don't fit are dropped from the end; the subtitle keeps priority.
+fn push_bottom_bar(buf: &mut String, cfg: &Config, plot: &BraillePlot, width: usize) {
+    let named: Vec<_> = plot.layers.iter().filter(|l| !l.name.is_empty()).collect();
+    let entries = if cfg.legend && named.len() >= 2 {
+        named.as_slice()
+    } else {
+        &[]
+    };
+
+    let sub_w = cfg.subtitle.as_ref().map_or(0, |s| {
+        let len = s.chars().count();
+        if len + TITLE_PADDING <= width {
+            len + TITLE_PADDING + 2 // keep a rule on either side
+        } else {
+            0
+        }
+    });
+
+    // Each entry is `⣿ name` plus two spaces (gap or outer padding).
+    let mut legend_w = 0;
+    let mut shown = 0;
+    for layer in entries {
+        let w = layer.name.chars().count() + LEGEND_SWATCH_WIDTH + 2;
+        if sub_w + legend_w + w + 1 > width {
+            break;
+        }
+        legend_w += w;
+        shown += 1;
+    }
+
+    let left = if shown > 0 {
+        width - legend_w - 1
+    } else {
+        width
+    };
+    if let Some(sub) = &cfg.subtitle {
+        push_centered(buf, sub, left, cfg);
+    } else {
+        buf.push_str(&box_chars(cfg).h.repeat(left));
+    }
+
+    if shown > 0 {
+        buf.push(' ');
+        for (i, layer) in entries[..shown].iter().enumerate() {
+            if i > 0 {
+                buf.push_str("  ");
+            }
+            buf.push_str(&paint(cfg, &layer.color, &cfg.glyphs.swatch().to_string()));
+            buf.push(' ');
+            buf.push_str(&layer.name);
+        }
+        buf.push(' ');
+        buf.push_str(box_chars(cfg).h);
+    }
+}
+
+/// Evenly spaced, non-overlapping tick labels under the plot area.
+///
+/// Each label is centred on the character column its tick time maps to,
+/// assuming the dot columns are evenly spaced over `plot.x_span` (see
+/// [`BraillePlot::x_span`]).
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)] // ticks lie inside the span of dot columns
+fn push_x_labels(buf: &mut String, cfg: &Config, plot: &BraillePlot) {
+    let mut row = vec![' '; cfg.x_chars];
+    let dots = plot.dot_columns();
+
+    let placed = matches!(cfg.style, PlotStyle::Line | PlotStyle::Scatter { .. });
+
+    if let Some((lo, hi)) = plot.x_span
+        && hi > lo
+        && dots > 1
+    {
+        // Guess a label width, then shrink the tick count until labels fit.
+        let mut max_ticks = cfg.x_chars / 8;
+        let (ticks, labels) = loop {
+            let (ticks, step) = cfg.x_format.ticks(lo, hi, max_ticks);
+            let labels: Vec<String> = ticks.iter().map(|&t| cfg.x_format.label(t, step)).collect();
+            let widest = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+            let fit = cfg.x_chars / (widest + X_LABEL_GAP);
+            if labels.len() <= fit || max_ticks <= 1 {
+                break (ticks, labels);
+            }
+            max_ticks = fit.min(max_ticks - 1).max(1);
+        };
+
+        let mut free_from = 0; // first column not yet taken by a label (+ gap)
+        for (t, label) in ticks.iter().zip(&labels) {
+            let at = (t - lo) / (hi - lo);
+            let dot = if placed {
+                (at * (dots - 1) as f64).round() as usize
+            } else {
+                ((at * dots as f64) as usize).min(dots - 1)
+            };
+            let centre = dot / cfg.glyphs.cols();
+            let len = label.chars().count();
+            if len > cfg.x_chars {
+                continue;
+            }
+            let start = centre.saturating_sub(len / 2).min(cfg.x_chars - len);
+            if start < free_from {
+                continue;
+            }
+            for (cell, ch) in row[start..start + len].iter_mut().zip(label.chars()) {
+                *cell = ch;
+            }
+            free_from = start + len + X_LABEL_GAP;
+        }
+    }
+    buf.extend(row);
+}
+
+/// Per-row y labels (extremes on the first/last row, round ticks on the row
+/// they fall in) plus, when gridlines are on, the dot row to draw them at.
+fn y_axis(cfg: &Config) -> (Vec<Option<String>>, Vec<Option<usize>>) {
+    let fmt = |v: f64| y_label(v, DECIMAL_PRECISION);
+    let last = cfg.y_chars - 1;
+    let mut labels = vec![None; cfg.y_chars];
+    let mut grid = vec![None; cfg.y_chars];
+    labels[0] = Some(fmt(cfg.y_max));
+    labels[last] = Some(fmt(cfg.y_min));
+
+    for t in y_ticks(cfg.y_min, cfg.y_max, cfg.y_scale) {
+        let px = y_to_px(t, cfg);
+        let row = px / cfg.glyphs.rows();
+        if labels[row].is_none() {
+            labels[row] = Some(fmt(t));
+            if cfg.gridlines {
+                grid[row] = Some(px % cfg.glyphs.rows());
+            }
+        }
+    }
+    (labels, grid)
+}
+
+/// A single dot at sub-row `dot` in every other cell: a faint dotted rule
+/// that never hides data (only drawn in otherwise blank cells).
+#[inline]
+fn grid_char(glyphs: Glyphs, col: usize, dot: usize) -> char {
+    let mask = if col.is_multiple_of(2) {
+        1u8 << (dot * glyphs.cols())
+    } else {
+        0
+    };
+    glyphs.encode(mask)
+}
+
+/// Map the dot columns under cell (`char_idx`,`row`) to a single glyph plus
+/// the color that owns the cell (`None` for a blank cell).
 #[inline]
-fn braille_char(char_idx: usize, row: usize, plot: &BraillePlot) -> char {
-    let left = char_idx * 2;
-    let right = left + 1;
-    let base_y = row * 4;
+fn cell_char(
+    char_idx: usize,
+    row: usize,
+    plot: &BraillePlot,
+    cfg: &Config,
+) -> (char, Option<AnsiCode>) {
+    let (cols, rows) = (cfg.glyphs.cols(), cfg.glyphs.rows());
+    let base_y = row * rows;
     let mut mask = 0u8;
+    let mut owner = None;
+    let mut writers = 0usize;
 
-    let mut stamp = |step: &GraphTimeStep, col: usize| {
-        for y in 0..4 {
-            let g = base_y + y;
-            if g >= step.min && g <= step.max {
-                mask |= 1 << BRAILLE_DOT_POSITIONS[col][y];
+    for layer in &plot.layers {
+        let (mut layer_mask, color) = match &layer.scatter {
+            Some(dots) => {
+                let (m, density) = dots.cell(char_idx, row);
+                (m, density.unwrap_or(layer.color))
+            }
+            None => (0u8, layer.color),
+        };
+        for x in 0..cols {
+            if let Some(Some(step)) = layer.steps.get(char_idx * cols + x) {
+                for y in 0..rows {
+                    if (step.min..=step.max).contains(&(base_y + y)) {
+                        layer_mask |= 1 << (y * cols + x);
+                    }
+                }
             }
         }
-    };
+        if layer_mask != 0 {
+            mask |= layer_mask;
+            owner = Some(color);
+            writers += 1;
+        }
+    }
+
+    if let (Overlap::Color(c), 2..) = (cfg.overlap, writers) {
+        owner = Some(c);
+    }
+    (cfg.glyphs.encode(mask), owner)
+}
 
-    if let Some(s) = plot.steps.get(left) {
-        stamp(s, 0);
+/// One row of plot cells, with a grid rule at sub-row `grid` in blank cells.
+fn push_cells(out: &mut String, row: usize, grid: Option<usize>, plot: &BraillePlot, cfg: &Config) {
+    let mut current = None;
+    for col in 0..cfg.x_chars {
+        let (mut ch, mut color) = cell_char(col, row, plot, cfg);
+        if let (None, Some(dot)) = (color, grid) {
+            ch = grid_char(cfg.glyphs, col, dot);
+            color = Some(GRID_COLOR);
+        }
+        if let Some(c) = color
+            && cfg.colored
+            && color != current
+        {
+            out.push_str(c.as_str());
+            current = color;
+        }
+        out.push(ch);
     }
-    if let Some(s) = plot.steps.get(right) {
-        stamp(s, 1);
+    if current.is_some() {
+        out.push_str(AnsiCode::reset().as_str());
     }
+}
 
-    char::from_u32(BRAILLE_UNICODE_BASE + u32::from(mask)).unwrap()
+/// Width and height in characters of the frame [`build_frame`] draws.
+#[inline]
+#[must_use]
+pub fn frame_size(cfg: &Config) -> (usize, usize) {
+    (
+        cfg.x_chars + cfg.label_width() + LABEL_GUTTER + BORDER_WIDTH,
+        cfg.y_chars + FRAME_ROWS,
+    )
 }
 
 /// Render a complete frame into a single `String`.
+///
+/// # Errors
+///
+/// [`GraphError::GraphTooSmall`] below the minimum plot size.
 pub fn build_frame(cfg: &Config, plot: &BraillePlot) -> Result<String, GraphError> {
-    if cfg.x_chars < MIN_GRAPH_WIDTH || cfg.y_chars < MIN_GRAPH_HEIGHT {
+    check_size(cfg, MIN_GRAPH_HEIGHT)?;
+    Ok(draw_frame(cfg, plot))
+}
+
+/// [`GraphError::GraphTooSmall`] if `cfg` is narrower than
+/// [`MIN_GRAPH_WIDTH`] or shorter than `min_height`.
+pub(super) fn check_size(cfg: &Config, min_height: usize) -> Result<(), GraphError> {
+    if cfg.x_chars < MIN_GRAPH_WIDTH || cfg.y_chars < min_height {
         return Err(GraphError::GraphTooSmall {
             want_w: MIN_GRAPH_WIDTH,
-            want_h: MIN_GRAPH_HEIGHT,
+            want_h: min_height,
             got_w: cfg.x_chars,
             got_h: cfg.y_chars,
         });
     }
+    Ok(())
+}
+
+/// `title`, the first line of a frame, with tees instead of corners to join
+/// it to the frame above.
+pub(super) fn tee_title(cfg: &Config, title: &str) -> String {
+    let b = box_chars(cfg);
+    match title.strip_prefix(b.tl).and_then(|t| t.strip_suffix(b.tr)) {
+        Some(bar) => format!("{}{bar}{}", b.lt, b.rt),
+        None => title.to_owned(),
+    }
+}
 
-    let hi_lbl = format!("{:.*}", DECIMAL_PRECISION, cfg.y_max);
-    let lo_lbl = format!("{:.*}", DECIMAL_PRECISION, cfg.y_min);
-    let lbl_w = hi_lbl.len().max(lo_lbl.len());
-    let line_len = cfg.x_chars + lbl_w + LABEL_GUTTER + BORDER_WIDTH;
+/// [`build_frame`] without the size check.
+pub(super) fn draw_frame(cfg: &Config, plot: &BraillePlot) -> String {
+    let lbl_w = cfg.label_width();
+    let (labels, grid) = y_axis(cfg);
+    let (line_len, _) = frame_size(cfg);
 
+    let b = box_chars(cfg);
     let mut out = String::with_capacity(line_len * (cfg.y_chars + 4));
 
     // Title bar
-    out.push_str(TL);
-    push_centered(&mut out, &cfg.title, line_len - BORDER_WIDTH, &cfg.color);
-    out.push_str(TR);
+    out.push_str(b.tl);
+    push_centered(&mut out, &cfg.title, line_len - BORDER_WIDTH, cfg);
+    out.push_str(b.tr);
     out.push('\n');
 
     // Top Padding
-    out.push_str(V);
+    out.push_str(b.v);
     out.push_str(&" ".repeat(line_len - BORDER_WIDTH));
-    out.push_str(V);
-    out.push_str("\n");
+    out.push_str(b.v);
+    out.push('\n');
 
     // Graph rows
     for row in 0..cfg.y_chars {
-        out.push_str(V);
-        if row == 0 {
-            out.push_str(&format!("{hi_lbl:>lbl_w$}"));
-        } else if row + 1 == cfg.y_chars {
-            out.push_str(&format!("{lo_lbl:>lbl_w$}"));
-        } else {
-            out.push_str(&" ".repeat(lbl_w));
-        }
+        out.push_str(b.v);
+        let _ = write!(out, "{:>lbl_w$}", labels[row].as_deref().unwrap_or(""));
         out.push(' ');
-        out.push_str(cfg.color.as_str());
-        for col in 0..cfg.x_chars {
-            out.push(braille_char(col, row, plot));
-        }
-        out.push_str(AnsiCode::reset().as_str());
-        out.push_str(V);
+        push_cells(&mut out, row, grid[row], plot, cfg);
+        out.push_str(b.v);
         out.push('\n');
     }
 
-    // Bottom Padding
-    out.push_str(V);
-    out.push_str(&" ".repeat(line_len - BORDER_WIDTH));
-    out.push_str(V);
-    out.push_str("\n");
+    // X-axis labels (blank padding row when there is no time span)
+    out.push_str(b.v);
+    out.push_str(&" ".repeat(lbl_w + LABEL_GUTTER));
+    push_x_labels(&mut out, cfg, plot);
+    out.push_str(b.v);
+    out.push('\n');
 
     // Bottom bar
-    out.push_str(BL);
-    if let Some(sub) = &cfg.subtitle {
-        push_centered(&mut out, sub, line_len - BORDER_WIDTH, &cfg.color);
-    } else {
-        out.push_str(&H.repeat(line_len - BORDER_WIDTH));
+    out.push_str(b.bl);
+    push_bottom_bar(&mut out, cfg, plot, line_len - BORDER_WIDTH);
+    out.push_str(b.br);
+    out.push('\n');
+    out
+}
+
+/// The plot area alone, for prompts, status bars and table cells: one line
+/// per character row (no trailing newline), without border, labels or
+/// cursor control and at any size down to a single cell.
+#[must_use]
+pub fn build_sparkline(cfg: &Config, plot: &BraillePlot) -> String {
+    let mut out = String::with_capacity(cfg.y_chars * (cfg.x_chars * 4 + 1));
+    for row in 0..cfg.y_chars {
+        if row > 0 {
+            out.push('\n');
+        }
+        push_cells(&mut out, row, None, plot, cfg);
     }
-    out.push_str(BR);
-    out.push_str("\n");
-    Ok(out)
+    out
+}
+
+/// Hides the cursor on construction and shows it again on Drop.
+/// Derefs to the wrapped writer; a guard built with `hide == false` is inert.
+struct CursorGuard<'a, W: Write> {
+    out: &'a mut W,
+    hide: bool,
 }
 
-/// Hides the cursor on construction and shows it again on Drop
-struct CursorGuard;
+impl<'a, W: Write> CursorGuard<'a, W> {
+    fn new(out: &'a mut W, hide: bool) -> Self {
+        if hide {
+            // hide → ESC[?25l
+            let _ = write!(out, "\x1b[?25l");
+        }
+        CursorGuard { out, hide }
+    }
+}
 
-impl CursorGuard {
-    fn new() -> Self {
-        // hide → ESC[?25l
-        let _ = write!(stdout(), "\x1b[?25l");
-        CursorGuard
+impl<W: Write> Deref for CursorGuard<'_, W> {
+    type Target = W;
+    fn deref(&self) -> &W {
+        self.out
     }
 }
 
-impl Drop for CursorGuard {
+impl<W: Write> DerefMut for CursorGuard<'_, W> {
+    fn deref_mut(&mut self) -> &mut W {
+        self.out
+    }
+}
+
+impl<W: Write> Drop for CursorGuard<'_, W> {
     fn drop(&mut self) {
-        // show → ESC[?25h
-        let _ = write!(stdout(), "\x1b[?25h");
-        let _ = stdout().flush();
+        if self.hide {
+            // show → ESC[?25h
+            let _ = write!(self.out, "\x1b[?25h");
+        }
+        let _ = self.out.flush();
     }
 }
 
@@ -189,6 +499,8 @@ enum Strategy {
 pub struct Renderer {
     strat: Strategy,
     first_frame: bool,
+    hide_cursor: bool,
+    clear_screen: bool,
 }
 
 impl Renderer {
@@ -198,8 +510,17 @@ impl Renderer {
         Self {
             strat: Strategy::Full,
             first_frame: true,
+            hide_cursor: true,
+            clear_screen: true,
         }
     }
+    /// [`Renderer::full`] without cursor control: each frame is appended as
+    /// plain lines, for files, pipes and CI logs.
+    #[inline]
+    #[must_use]
+    pub fn plain() -> Self {
+        Self::full().hide_cursor(false).clear_screen(false)
+    }
     #[inline]
     #[must_use]
     pub fn delta() -> Self {
@@ -208,25 +529,99 @@ impl Renderer {
                 prev_hash: Vec::new(),
             },
             first_frame: true,
+            hide_cursor: true,
+            clear_screen: true,
         }
     }
 
-    /// Calls `build_frame` and either renders it in full or only
+    /// Hide the cursor while a frame is written (default: on).
+    #[inline]
+    #[must_use]
+    pub fn hide_cursor(mut self, on: bool) -> Self {
+        self.hide_cursor = on;
+        self
+    }
+
+    /// Clear the screen before the first frame and, for [`Renderer::full`],
+    /// home the cursor before every frame (default: on).  Turn off to
+    /// append frames to a file, socket or buffer as plain text.
+    #[inline]
+    #[must_use]
+    pub fn clear_screen(mut self, on: bool) -> Self {
+        self.clear_screen = on;
+        self
+    }
+
+    /// [`Renderer::render_to`] on a locked stdout.
+    ///
+    /// # Errors
+    ///
+    /// As [`Renderer::render_to`].
+    pub fn render(&mut self, cfg: &Config, plot: &BraillePlot) -> Result<(), GraphError> {
+        self.render_to(&mut stdout().lock(), cfg, plot)
+    }
+
+    /// Calls `build_frame` and either writes it to `out` in full or only
     /// the lines that changed with delta.
     ///
     /// If using `Renderer::delta`, hash collision leads to an
     /// unnecessary redraw but no corruption.
-    pub fn render(&mut self, cfg: &Config, plot: &BraillePlot) -> Result<(), GraphError> {
+    ///
+    /// # Errors
+    ///
+    /// As [`build_frame`], and when writing to `out` fails.
+    pub fn render_to<W: Write>(
+        &mut self,
+        out: &mut W,
+        cfg: &Config,
+        plot: &BraillePlot,
+    ) -> Result<(), GraphError> {
         let frame = build_frame(cfg, plot)?;
-        let mut term = stdout().lock();
-        let _cursor = CursorGuard::new();
+        self.write_frame(out, &frame)
+    }
+
+    /// [`Renderer::render_panels_to`] on a locked stdout.
+    ///
+    /// # Errors
+    ///
+    /// As [`Renderer::render_panels_to`].
+    pub fn render_panels(
+        &mut self,
+        layout: Layout,
+        panels: &[Panel<'_>],
+    ) -> Result<(), GraphError> {
+        self.render_panels_to(&mut stdout().lock(), layout, panels)
+    }
+
+    /// Like [`Renderer::render_to`], for several plots [`compose`]d into
+    /// one frame.
+    ///
+    /// # Errors
+    ///
+    /// As [`compose`], and when writing to `out` fails.
+    pub fn render_panels_to<W: Write>(
+        &mut self,
+        out: &mut W,
+        layout: Layout,
+        panels: &[Panel<'_>],
+    ) -> Result<(), GraphError> {
+        let frame = compose(layout, panels)?;
+        self.write_frame(out, &frame)
+    }
+
+    fn write_frame<W: Write>(&mut self, out: &mut W, frame: &str) -> Result<(), GraphError> {
+        let mut term = CursorGuard::new(out, self.hide_cursor);
         if self.first_frame {
-            write!(term, "\x1b[2J")?;
+            if self.clear_screen {
+                write!(term, "\x1b[2J")?;
+            }
             self.first_frame = false;
         }
         match &mut self.strat {
             Strategy::Full => {
-                write!(term, "\x1b[H")?;
+                if self.clear_screen {
+                    write!(term, "\x1b[H")?;
+                }
                 term.write_all(frame.as_bytes())?;
             }
             Strategy::Delta { prev_hash } => {
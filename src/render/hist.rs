@@ -0,0 +1,241 @@
+//! Value distributions: bucket counts drawn as vertical bars.
+//!
+//! [`Histogram::steps`] lays the buckets out over the dot columns of a plot,
+//! ready for [`preprocess_to_braille`](super::preprocess_to_braille) with
+//! [`PlotStyle::Area`](crate::core::config::PlotStyle::Area).
+
+use std::fmt::Write as _;
+
+use crate::core::{constants::DECIMAL_PRECISION, data::DataTimeStep, error::GraphError};
+
+/// Percentiles [`Histogram::summary`] reports unless told otherwise.
+pub const SUMMARY_PERCENTILES: [f64; 3] = [50.0, 90.0, 99.0];
+
+/// How many buckets [`Histogram::new`] uses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BinRule {
+    /// Exactly this many buckets.
+    Count(usize),
+    /// ⌈log₂ n⌉ + 1 buckets; fine for roughly normal data.
+    #[default]
+    Sturges,
+    /// Buckets 2·IQR·n^(-1/3) wide; robust to outliers and long tails.
+    /// Falls back to Sturges when the IQR is zero.
+    FreedmanDiaconis,
+}
+
+impl BinRule {
+    /// Bucket count for `sorted` (finite, ascending, non-empty).
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )] // non-negative bucket counts, far below 2^52
+    fn buckets(self, sorted: &[f64]) -> usize {
+        let n = sorted.len() as f64;
+        let sturges = n.log2().ceil() as usize + 1;
+        match self {
+            Self::Count(k) => k,
+            Self::Sturges => sturges,
+            Self::FreedmanDiaconis => {
+                let iqr = nearest_rank(sorted, 75.0) - nearest_rank(sorted, 25.0);
+                let width = 2.0 * iqr / n.cbrt();
+                let span = sorted[sorted.len() - 1] - sorted[0];
+                if width > 0.0 {
+                    (span / width).ceil() as usize
+                } else {
+                    sturges
+                }
+            }
+        }
+    }
+}
+
+/// Bucket counts over `lo..=hi`, plus the sorted samples for summaries.
+pub struct Histogram {
+    pub lo: f64,
+    pub hi: f64,
+    pub counts: Vec<u64>,
+    sorted: Vec<f64>,
+}
+
+impl Histogram {
+    /// Count the finite `values` into buckets chosen by `rule`, but at most
+    /// `max_buckets` (one per dot column is the finest a plot can show).
+    ///
+    /// # Errors
+    ///
+    /// [`GraphError::EmptyData`] when no value is finite.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )] // value ratio within [0, 1] of the bucket count
+    pub fn new(values: &[f64], rule: BinRule, max_buckets: usize) -> Result<Self, GraphError> {
+        let mut sorted: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+        if sorted.is_empty() {
+            return Err(GraphError::EmptyData);
+        }
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let (mut lo, mut hi) = (sorted[0], sorted[sorted.len() - 1]);
+        if hi <= lo {
+            // single value: one bucket around it
+            lo -= 0.5;
+            hi += 0.5;
+        }
+        let k = rule.buckets(&sorted).clamp(1, max_buckets.max(1));
+        let mut counts = vec![0u64; k];
+        for v in &sorted {
+            let i = ((v - lo) / (hi - lo) * k as f64) as usize;
+            counts[i.min(k - 1)] += 1; // hi falls into the last bucket
+        }
+        Ok(Self {
+            lo,
+            hi,
+            counts,
+            sorted,
+        })
+    }
+
+    /// Width of one bucket.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // bucket counts are far below 2^52
+    pub fn bucket_width(&self) -> f64 {
+        (self.hi - self.lo) / self.counts.len() as f64
+    }
+
+    /// One step per dot column, at the value the column covers and as high
+    /// as its bucket's count (`log10(1 + count)` with `log`).  Bars wider
+    /// than two dots are separated by a blank column.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // counts and dot columns are far below 2^52
+    pub fn steps(&self, dot_columns: usize, log: bool) -> Vec<DataTimeStep> {
+        let k = self.counts.len();
+        let gap = dot_columns >= 3 * k;
+        let span = self.hi - self.lo;
+        (0..dot_columns)
+            .map(|c| {
+                let bucket = c * k / dot_columns;
+                let first = c > 0 && (c - 1) * k / dot_columns != bucket;
+                let n = self.counts[bucket] as f64;
+                let y = if gap && first {
+                    f64::NAN
+                } else if log {
+                    n.ln_1p() / std::f64::consts::LN_10
+                } else {
+                    n
+                };
+                DataTimeStep {
+                    time: self.lo + (c as f64 + 0.5) / dot_columns as f64 * span,
+                    min: y,
+                    max: y,
+                }
+            })
+            .collect()
+    }
+
+    /// Largest bar height, as plotted by [`steps`](Self::steps).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // counts are far below 2^52
+    pub fn peak(&self, log: bool) -> f64 {
+        let n = self.counts.iter().copied().max().unwrap_or(0) as f64;
+        if log {
+            n.ln_1p() / std::f64::consts::LN_10
+        } else {
+            n
+        }
+    }
+
+    /// `n=… min=… mean=… p50=… max=…`, with the requested percentiles
+    /// (0–100) between mean and max.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // sample counts are far below 2^52
+    pub fn summary(&self, percentiles: &[f64]) -> String {
+        let n = self.sorted.len();
+        let mean = self.sorted.iter().sum::<f64>() / n as f64;
+        let fmt = |v: f64| format!("{v:.DECIMAL_PRECISION$}");
+        let mut s = format!("n={n} min={} mean={}", fmt(self.sorted[0]), fmt(mean));
+        for &p in percentiles {
+            let _ = write!(s, " p{p}={}", fmt(nearest_rank(&self.sorted, p)));
+        }
+        let _ = write!(s, " max={}", fmt(self.sorted[n - 1]));
+        s
+    }
+}
+
+/// Nearest-rank `p`-th percentile (0–100) of ascending `sorted`.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)] // rank within 0..len
+fn nearest_rank(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(n: u32) -> Vec<f64> {
+        (0..n).map(f64::from).collect()
+    }
+
+    #[test]
+    fn bin_rules_pick_bucket_counts() {
+        // ⌈log₂ n⌉ + 1
+        assert_eq!(BinRule::Sturges.buckets(&[7.0]), 1);
+        assert_eq!(BinRule::Sturges.buckets(&range(8)), 4);
+        assert_eq!(BinRule::Sturges.buckets(&range(100)), 8);
+        assert_eq!(BinRule::Count(5).buckets(&range(100)), 5);
+        // IQR 74 - 25 = 49: buckets 2 · 49 / ∛100 ≈ 21.1 wide over a span of 99
+        assert_eq!(BinRule::FreedmanDiaconis.buckets(&range(100)), 5);
+    }
+
+    #[test]
+    fn freedman_diaconis_falls_back_to_sturges_without_spread() {
+        let mut sorted = vec![5.0; 10];
+        sorted.push(9.0);
+        assert_eq!(
+            BinRule::FreedmanDiaconis.buckets(&sorted),
+            BinRule::Sturges.buckets(&sorted)
+        );
+    }
+
+    #[test]
+    fn values_on_an_edge_go_to_the_bucket_above() {
+        let hist = Histogram::new(&range(5), BinRule::Count(4), 10).unwrap();
+        assert_eq!((hist.lo, hist.hi), (0.0, 4.0));
+        assert!((hist.bucket_width() - 1.0).abs() < f64::EPSILON);
+        // 1, 2 and 3 start a bucket; 4 is the upper bound and stays in the last
+        assert_eq!(hist.counts, [1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn bucket_count_is_capped_and_single_values_get_one_bucket() {
+        let hist = Histogram::new(&range(100), BinRule::Count(50), 3).unwrap();
+        assert_eq!(hist.counts, [33, 33, 34]);
+
+        let hist = Histogram::new(&[2.0, f64::NAN], BinRule::Sturges, 10).unwrap();
+        assert_eq!((hist.lo, hist.hi), (1.5, 2.5));
+        assert_eq!(hist.counts, [1]);
+
+        assert!(matches!(
+            Histogram::new(&[f64::NAN], BinRule::Sturges, 10),
+            Err(GraphError::EmptyData)
+        ));
+    }
+
+    #[test]
+    fn summary_reports_the_requested_percentiles() {
+        let hist = Histogram::new(&range(101), BinRule::Sturges, 10).unwrap();
+        assert_eq!(
+            hist.summary(&SUMMARY_PERCENTILES),
+            "n=101 min=0.00 mean=50.00 p50=50.00 p90=90.00 p99=99.00 max=100.00"
+        );
+        assert_eq!(hist.summary(&[]), "n=101 min=0.00 mean=50.00 max=100.00");
+    }
+}
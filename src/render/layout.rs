@@ -0,0 +1,287 @@
+//! Several plots in one frame: stacked, side by side or in a grid.
+//!
+//! Every panel is an ordinary [`build_frame`] with its own [`Config`] (size,
+//! title, y range) and [`BraillePlot`]; [`compose`] only places the frames,
+//! joining stacked ones that share an x axis into one.
+//! The result is a plain multi-line string, so
+//! [`Renderer::delta`](super::Renderer::delta) still redraws only the lines
+//! that changed.
+
+use super::{
+    braille::BraillePlot,
+    frame::{check_size, draw_frame, frame_size, tee_title},
+};
+use crate::core::{
+    config::Config,
+    constants::{FRAME_ROWS, MIN_PANEL_HEIGHT},
+    error::GraphError,
+};
+
+/// Blank columns between two panels side by side.
+const PANEL_GAP: usize = 1;
+
+/// How panels are arranged, in the order they are given.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// One above the other.
+    #[default]
+    Stack,
+    /// Side by side.
+    Row,
+    /// Left to right, top to bottom, this many panels per row.
+    Grid { cols: usize },
+}
+
+impl Layout {
+    /// Panels per row for `n` panels.
+    #[inline]
+    #[must_use]
+    pub fn columns(self, n: usize) -> usize {
+        match self {
+            Self::Stack => 1,
+            Self::Row => n.max(1),
+            Self::Grid { cols } => cols.clamp(1, n.max(1)),
+        }
+    }
+
+    /// Outer frame size of each of `n` panels sharing `w`×`h` characters,
+    /// for [`ConfigBuilder::outer_size`](crate::core::config::ConfigBuilder::outer_size).
+    /// Leftover characters go to the first rows and columns.  Stacked
+    /// panels are sized to share one x axis (see [`compose`]).
+    #[must_use]
+    pub fn panel_sizes(self, n: usize, (w, h): (usize, usize)) -> Vec<(usize, usize)> {
+        let cols = self.columns(n);
+        let rows = n.div_ceil(cols);
+        let w = w.saturating_sub(PANEL_GAP * (cols - 1));
+        // joined, every panel but the last loses its padding, x-label and
+        // bottom rows, and the last its padding row
+        let h = match self {
+            Self::Stack => (h + n * (FRAME_ROWS - 1)).saturating_sub(2),
+            _ => h,
+        };
+        let share =
+            |total: usize, parts: usize, i: usize| total / parts + usize::from(i < total % parts);
+        (0..n)
+            .map(|i| (share(w, cols, i % cols), share(h, rows, i / cols)))
+            .collect()
+    }
+}
+
+/// One plot of a composed frame.
+#[derive(Clone, Copy)]
+pub struct Panel<'a> {
+    pub cfg: &'a Config,
+    pub plot: &'a BraillePlot,
+}
+
+/// Build every panel's frame and arrange them per `layout`.  Panels that
+/// are narrower or shorter than others in their column or row are padded
+/// with blanks.
+///
+/// Stacked panels that would draw the same x labels and bottom bar (same
+/// width and x span, as for one time range) are joined into one frame:
+/// each keeps its title bar and plot rows, and the labels and bottom bar
+/// are drawn once, under the last.
+///
+/// # Errors
+///
+/// [`GraphError::EmptyData`] without panels, or
+/// [`GraphError::GraphTooSmall`] when a panel is narrower than
+/// [`build_frame`](super::build_frame) allows or shorter than
+/// [`MIN_PANEL_HEIGHT`].
+pub fn compose(layout: Layout, panels: &[Panel<'_>]) -> Result<String, GraphError> {
+    if panels.is_empty() {
+        return Err(GraphError::EmptyData);
+    }
+    let cols = layout.columns(panels.len());
+    let mut frames = Vec::with_capacity(panels.len());
+    let mut widths = vec![0; cols];
+    for (i, p) in panels.iter().enumerate() {
+        check_size(p.cfg, MIN_PANEL_HEIGHT)?;
+        let size = frame_size(p.cfg);
+        widths[i % cols] = widths[i % cols].max(size.0);
+        frames.push((size, draw_frame(p.cfg, p.plot)));
+    }
+    if layout == Layout::Stack
+        && let Some(out) = join_stack(panels, &frames)
+    {
+        return Ok(out);
+    }
+
+    let mut out = String::new();
+    for row in frames.chunks(cols) {
+        let height = row.iter().map(|((_, h), _)| *h).max().unwrap_or(0);
+        let mut lines: Vec<_> = row.iter().map(|(_, f)| f.lines()).collect();
+        for _ in 0..height {
+            for (c, (((w, _), _), it)) in row.iter().zip(&mut lines).enumerate() {
+                if c > 0 {
+                    out.push_str(&" ".repeat(PANEL_GAP));
+                }
+                let pad = match it.next() {
+                    Some(line) => {
+                        out.push_str(line);
+                        widths[c] - w
+                    }
+                    None => widths[c],
+                };
+                out.push_str(&" ".repeat(pad));
+            }
+            out.push('\n');
+        }
+    }
+    Ok(out)
+}
+
+/// Stacked `frames` as one, sharing the last one's x labels and bottom bar;
+/// `None` unless every frame would draw the same two rows.
+fn join_stack(panels: &[Panel<'_>], frames: &[((usize, usize), String)]) -> Option<String> {
+    let lines: Vec<Vec<&str>> = frames.iter().map(|(_, f)| f.lines().collect()).collect();
+    // title bar, padding, plot rows, x labels, bottom bar
+    let axis = |l: &[&str]| l.len() - 2;
+    let last = lines.last()?;
+    if lines.iter().any(|l| l[axis(l)..] != last[axis(last)..]) {
+        return None;
+    }
+
+    let mut out = String::new();
+    for (i, (p, l)) in panels.iter().zip(&lines).enumerate() {
+        if i == 0 {
+            out.push_str(l[0]);
+        } else {
+            out.push_str(&tee_title(p.cfg, l[0]));
+        }
+        out.push('\n');
+        for row in &l[2..axis(l)] {
+            out.push_str(row);
+            out.push('\n');
+        }
+    }
+    for row in &last[axis(last)..] {
+        out.push_str(row);
+        out.push('\n');
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{core::data::DataTimeStep, render::preprocess_to_braille};
+
+    /// An `x_chars`×`y_chars` panel with a short rising line.
+    fn panel(x_chars: usize, y_chars: usize) -> (Config, BraillePlot) {
+        let cfg = Config::builder(x_chars, y_chars)
+            .y_range(0.0..=1.0)
+            .colored(false)
+            .build()
+            .unwrap();
+        let steps: Vec<_> = (0..4u32)
+            .map(|i| DataTimeStep {
+                time: f64::from(i),
+                min: f64::from(i) / 3.0,
+                max: f64::from(i) / 3.0,
+            })
+            .collect();
+        let plot = preprocess_to_braille(&steps, &cfg, false).unwrap();
+        (cfg, plot)
+    }
+
+    fn widths(frame: &str) -> Vec<usize> {
+        frame.lines().map(|l| l.chars().count()).collect()
+    }
+
+    #[test]
+    fn panel_sizes_share_leftovers_with_the_first_rows_and_columns() {
+        // 51 columns less two gaps: 49 = 17 + 16 + 16
+        assert_eq!(
+            Layout::Row.panel_sizes(3, (51, 20)),
+            [(17, 20), (16, 20), (16, 20)]
+        );
+        // 42 less one gap: 41 = 21 + 20; 31 rows over 3 rows of panels
+        assert_eq!(
+            Layout::Grid { cols: 2 }.panel_sizes(5, (42, 31)),
+            [(21, 11), (20, 11), (21, 10), (20, 10), (21, 10)]
+        );
+        // stacked: 24 rows less a title bar per panel, one x-label row and
+        // one bottom bar leave 19 plot rows (7 + 6 + 6)
+        let sizes = Layout::Stack.panel_sizes(3, (72, 24));
+        assert_eq!(sizes, [(72, 11), (72, 10), (72, 10)]);
+        let plot_rows: usize = sizes.iter().map(|(_, h)| h - FRAME_ROWS).sum();
+        assert_eq!(plot_rows + 3 + 2, 24);
+    }
+
+    #[test]
+    fn grid_columns_are_clamped_to_the_panel_count() {
+        assert_eq!(Layout::Grid { cols: 5 }.columns(2), 2);
+        assert_eq!(Layout::Grid { cols: 0 }.columns(3), 1);
+        assert_eq!(Layout::Grid { cols: 3 }.columns(0), 1);
+        assert_eq!(
+            Layout::Grid { cols: 5 }.panel_sizes(2, (41, 20)),
+            Layout::Row.panel_sizes(2, (41, 20))
+        );
+    }
+
+    #[test]
+    fn compose_pads_shorter_and_narrower_panels() {
+        let (short_cfg, short_plot) = panel(20, 7);
+        let (tall_cfg, tall_plot) = panel(30, 9);
+        let panels = [
+            Panel {
+                cfg: &short_cfg,
+                plot: &short_plot,
+            },
+            Panel {
+                cfg: &tall_cfg,
+                plot: &tall_plot,
+            },
+        ];
+        let (short_w, short_h) = frame_size(&short_cfg);
+        let (tall_w, tall_h) = frame_size(&tall_cfg);
+
+        // side by side: as tall as the taller, the shorter padded below
+        let row = compose(Layout::Row, &panels).unwrap();
+        assert_eq!(widths(&row), vec![short_w + PANEL_GAP + tall_w; tall_h]);
+        for line in row.lines().skip(short_h) {
+            assert!(line.chars().take(short_w).all(|c| c == ' '));
+        }
+
+        // stacked frames of different widths are not joined, and the
+        // narrower one is padded to the wider
+        let stack = compose(Layout::Stack, &panels).unwrap();
+        assert_eq!(widths(&stack), vec![tall_w; short_h + tall_h]);
+        assert!(stack.lines().nth(short_h - 1).unwrap().ends_with(' '));
+    }
+
+    #[test]
+    fn compose_joins_stacked_panels_sharing_an_axis() {
+        let (a_cfg, a_plot) = panel(20, 5);
+        let (b_cfg, b_plot) = panel(20, 6);
+        let panels = [
+            Panel {
+                cfg: &a_cfg,
+                plot: &a_plot,
+            },
+            Panel {
+                cfg: &b_cfg,
+                plot: &b_plot,
+            },
+        ];
+        let frame = compose(Layout::Stack, &panels).unwrap();
+        let lines: Vec<_> = frame.lines().collect();
+        // two title bars, 5 + 6 plot rows, one x-label row and bottom bar
+        assert_eq!(lines.len(), 2 + 5 + 6 + 2);
+        assert!(lines[0].starts_with('┌'));
+        assert!(lines[6].starts_with('├') && lines[6].ends_with('┤'));
+        assert!(lines[14].starts_with('└'));
+
+        let (low_cfg, low_plot) = panel(20, MIN_PANEL_HEIGHT - 1);
+        let too_low = [Panel {
+            cfg: &low_cfg,
+            plot: &low_plot,
+        }];
+        assert!(matches!(
+            compose(Layout::Stack, &too_low),
+            Err(GraphError::GraphTooSmall { .. })
+        ));
+    }
+}
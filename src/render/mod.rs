@@ -1,7 +1,12 @@
 pub mod binner;
 pub mod braille;
 pub mod frame;
+pub mod hist;
+pub mod layout;
+pub mod term;
 
-pub use binner::{Binner, Strategy};
-pub use braille::{BraillePlot, preprocess_to_braille};
-pub use frame::Renderer;
+pub use binner::{Aggregate, Binner, Source, Strategy};
+pub use braille::{BraillePlot, Layer, preprocess_series, preprocess_to_braille};
+pub use frame::{Renderer, build_frame, build_sparkline, frame_size};
+pub use hist::{BinRule, Histogram};
+pub use layout::{Layout, Panel, compose};
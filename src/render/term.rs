@@ -0,0 +1,279 @@
+//! Raw keyboard input on the alternate screen, for interactive views.
+//!
+//! Like the cursor guard in [`frame`](super::frame), [`RawTerminal`] undoes
+//! everything it changed when dropped, and also before a panic message is
+//! printed, so a crash never leaves the shell in raw mode.
+
+use std::{
+    io::{self, Write, stdout},
+    sync::Once,
+};
+
+/// A key press, as far as the viewers care.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Left,
+    Right,
+    Up,
+    Down,
+    Esc,
+    /// Left button pressed or dragged over this cell (0-based), with
+    /// [`RawTerminal::capture_mouse`].
+    Mouse {
+        col: usize,
+        row: usize,
+    },
+    /// Any other byte; control keys arrive as control characters
+    /// (`'\u{3}'` for Ctrl-C).
+    Char(char),
+}
+
+/// Split what was read into keys, and how many bytes they took: an escape
+/// sequence cut off at the end is left for the next read, as is a lone
+/// Esc when `more` input is already waiting.  Unknown escape sequences are
+/// dropped whole.
+fn parse_keys(bytes: &[u8], more: bool) -> (Vec<Key>, usize) {
+    let mut keys = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i..] {
+            [0x1b, b'[' | b'O', ..] => {
+                // parameters, then a final byte in `@`..=`~`
+                let rest = &bytes[i + 2..];
+                let Some(end) = rest.iter().position(|b| (0x40..=0x7e).contains(b)) else {
+                    break;
+                };
+                keys.extend(match rest[end] {
+                    b'A' => Some(Key::Up),
+                    b'B' => Some(Key::Down),
+                    b'C' => Some(Key::Right),
+                    b'D' => Some(Key::Left),
+                    b'M' if rest.first() == Some(&b'<') => sgr_mouse(&rest[1..end]),
+                    _ => None,
+                });
+                i += 2 + end + 1;
+            }
+            [0x1b] if more => break,
+            [0x1b, ..] => {
+                keys.push(Key::Esc);
+                i += 1;
+            }
+            [b, ..] => {
+                keys.push(Key::Char(char::from(b)));
+                i += 1;
+            }
+            [] => break,
+        }
+    }
+    (keys, i)
+}
+
+/// `button;col;row` of an SGR mouse press (1-based cells): only the left
+/// button, pressed (0) or dragged (32), is reported.
+fn sgr_mouse(params: &[u8]) -> Option<Key> {
+    let mut fields = std::str::from_utf8(params)
+        .ok()?
+        .split(';')
+        .map(|f| f.parse::<usize>().ok());
+    let (button, col, row) = (fields.next()??, fields.next()??, fields.next()??);
+    (button & !32 == 0).then(|| Key::Mouse {
+        col: col.saturating_sub(1),
+        row: row.saturating_sub(1),
+    })
+}
+
+/// Installs the panic hook that leaves raw mode, on the first `enter`.
+static PANIC_HOOK: Once = Once::new();
+
+/// Raw mode, alternate screen and hidden cursor for as long as it lives.
+pub struct RawTerminal {
+    /// Start of an escape sequence the last read cut off.
+    pending: Vec<u8>,
+}
+
+impl RawTerminal {
+    /// Switch stdin to raw mode and stdout to the alternate screen.  Fails
+    /// when either is not a terminal.
+    ///
+    /// # Errors
+    ///
+    /// When stdin or stdout is not a terminal, or its settings cannot be
+    /// changed.
+    pub fn enter() -> io::Result<Self> {
+        sys::enter()?;
+        // One hook serves every session: `restore` does nothing outside one.
+        PANIC_HOOK.call_once(|| {
+            let previous = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                sys::restore();
+                previous(info);
+            }));
+        });
+        let mut out = stdout();
+        write!(out, "\x1b[?1049h\x1b[?25l")?;
+        out.flush()?;
+        Ok(Self {
+            pending: Vec::new(),
+        })
+    }
+
+    /// Report left-button clicks and drags as [`Key::Mouse`] (SGR mouse
+    /// mode; terminals without it send nothing).  Ends with the session.
+    ///
+    /// # Errors
+    ///
+    /// When writing to stdout fails.
+    pub fn capture_mouse(&mut self) -> io::Result<()> {
+        let mut out = stdout();
+        write!(out, "\x1b[?1002h\x1b[?1006h")?;
+        out.flush()
+    }
+
+    /// Keys pressed since the last call.  Waits up to a tenth of a second
+    /// and returns no keys if none arrived, so callers can poll for other
+    /// changes (e.g. the terminal size) in between.
+    ///
+    /// # Errors
+    ///
+    /// When reading stdin fails.
+    pub fn read_keys(&mut self) -> io::Result<Vec<Key>> {
+        let mut buf = [0u8; 64];
+        let n = sys::read(&mut buf)?;
+        self.pending.extend_from_slice(&buf[..n]);
+        // a full buffer means the rest of a burst is still to come
+        let (keys, used) = parse_keys(&self.pending, n == buf.len());
+        self.pending.drain(..used);
+        Ok(keys)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        sys::restore();
+    }
+}
+
+#[cfg(unix)]
+mod sys {
+    use std::{
+        io::{self, Write, stdin, stdout},
+        sync::Mutex,
+    };
+
+    use rustix::termios::{
+        OptionalActions, OutputModes, SpecialCodeIndex, Termios, isatty, tcgetattr, tcsetattr,
+    };
+
+    /// Settings to go back to; taken by the first `restore`.
+    static SAVED: Mutex<Option<Termios>> = Mutex::new(None);
+
+    pub fn enter() -> io::Result<()> {
+        if !isatty(stdin()) || !isatty(stdout()) {
+            return Err(io::Error::other("interactive mode needs a terminal"));
+        }
+        let saved = tcgetattr(stdin())?;
+        let mut raw = saved.clone();
+        raw.make_raw();
+        // keep `\n` → `\r\n`, so output written meanwhile stays readable
+        raw.output_modes.insert(OutputModes::OPOST);
+        raw.special_codes[SpecialCodeIndex::VMIN] = 0;
+        raw.special_codes[SpecialCodeIndex::VTIME] = 1;
+        tcsetattr(stdin(), OptionalActions::Flush, &raw)?;
+        *SAVED
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(saved);
+        Ok(())
+    }
+
+    pub fn restore() {
+        let saved = SAVED
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take();
+        if let Some(t) = saved {
+            let _ = tcsetattr(stdin(), OptionalActions::Now, &t);
+            let mut out = stdout();
+            let _ = write!(out, "\x1b[?1002l\x1b[?1006l\x1b[?25h\x1b[?1049l");
+            let _ = out.flush();
+        }
+    }
+
+    pub fn read(buf: &mut [u8]) -> io::Result<usize> {
+        Ok(rustix::io::read(stdin(), buf)?)
+    }
+}
+
+#[cfg(not(unix))]
+mod sys {
+    use std::io;
+
+    pub fn enter() -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "interactive mode needs a Unix terminal",
+        ))
+    }
+
+    pub fn restore() {}
+
+    pub fn read(_: &mut [u8]) -> io::Result<usize> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_and_merged_mouse_reports() {
+        let bytes = b"\x1b[<0;5;3M\x1b[<32;6;3Mq\x1b[A\x1bOD";
+        let (keys, used) = parse_keys(bytes, false);
+        assert_eq!(used, bytes.len());
+        assert_eq!(
+            keys,
+            [
+                Key::Mouse { col: 4, row: 2 },
+                Key::Mouse { col: 5, row: 2 },
+                Key::Char('q'),
+                Key::Up,
+                Key::Left,
+            ]
+        );
+    }
+
+    #[test]
+    fn other_mouse_events_are_dropped_whole() {
+        // release, right button, wheel
+        let bytes = b"\x1b[<0;5;3m\x1b[<2;5;3M\x1b[<64;5;3Mx";
+        assert_eq!(
+            parse_keys(bytes, false),
+            (vec![Key::Char('x')], bytes.len())
+        );
+    }
+
+    #[test]
+    fn partial_sequences_wait_for_more() {
+        assert_eq!(parse_keys(b"a\x1b[<0;12", false), (vec![Key::Char('a')], 1));
+        assert_eq!(parse_keys(b"a\x1b[", false), (vec![Key::Char('a')], 1));
+        assert_eq!(parse_keys(b"\x1b", true), (vec![], 0));
+        assert_eq!(parse_keys(b"\x1b", false), (vec![Key::Esc], 1));
+    }
+
+    #[test]
+    fn reads_split_anywhere_give_the_same_keys() {
+        let bytes = b"\x1b[<0;12;7Mj\x1b[<32;13;7M\x1b[C";
+        let (whole, _) = parse_keys(bytes, false);
+        for cut in 0..=bytes.len() {
+            // as `read_keys` does: unparsed bytes stay pending
+            let mut pending = bytes[..cut].to_vec();
+            let (mut keys, used) = parse_keys(&pending, true);
+            pending.drain(..used);
+            pending.extend_from_slice(&bytes[cut..]);
+            let (rest, used) = parse_keys(&pending, false);
+            assert_eq!(used, pending.len(), "cut at {cut}");
+            keys.extend(rest);
+            assert_eq!(keys, whole, "cut at {cut}");
+        }
+    }
+}